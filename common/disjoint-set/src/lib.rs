@@ -0,0 +1,164 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// Union-find over the elements `0..len`, with union by size and path
+/// compression.
+///
+/// The members of each component are also chained in a cycle, so that they
+/// can be listed without scanning every element.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    next: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Creates `len` singleton components.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: alloc::vec![1; len],
+            next: (0..len).collect(),
+            components: len,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The number of components.
+    #[must_use]
+    pub const fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The representative of the component of `element`, compressing the
+    /// path to it.
+    pub fn find(&mut self, mut element: usize) -> usize {
+        while self.parent[element] != element {
+            let grandparent = self.parent[self.parent[element]];
+            self.parent[element] = grandparent;
+            element = grandparent;
+        }
+
+        element
+    }
+
+    /// Like [`DisjointSet::find`], without compressing.
+    #[must_use]
+    pub fn root(&self, mut element: usize) -> usize {
+        while self.parent[element] != element {
+            element = self.parent[element];
+        }
+
+        element
+    }
+
+    /// Merges the components of `a` and `b`, returning whether they were
+    /// distinct.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.next.swap(a, b);
+        self.components -= 1;
+
+        true
+    }
+
+    #[must_use]
+    pub fn same(&self, a: usize, b: usize) -> bool {
+        self.root(a) == self.root(b)
+    }
+
+    /// The size of the component of `element`.
+    #[must_use]
+    pub fn size(&self, element: usize) -> usize {
+        self.size[self.root(element)]
+    }
+
+    /// The members of the component of `element`, starting from it.
+    pub fn members(&self, element: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut current = Some(element);
+        core::iter::from_fn(move || {
+            let member = current?;
+            let next = self.next[member];
+            current = (next != element).then_some(next);
+            Some(member)
+        })
+    }
+
+    /// The representative of the largest component, the first one on ties.
+    #[must_use]
+    pub fn largest(&self) -> Option<usize> {
+        (0..self.len())
+            .filter(|&element| self.parent[element] == element)
+            .rev()
+            .max_by_key(|&root| self.size[root])
+    }
+
+    /// The representatives of all components.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(|&element| self.parent[element] == element)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut set = DisjointSet::new(6);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+
+        assert!(set.same(0, 3));
+        assert!(!set.same(0, 4));
+        assert_eq!(set.find(3), set.root(0));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.size(5), 1);
+        assert_eq!(set.components(), 3);
+        assert_eq!(set.roots().count(), 3);
+    }
+
+    #[test]
+    fn test_members() {
+        let mut set = DisjointSet::new(7);
+        set.union(4, 5);
+        set.union(1, 2);
+        set.union(2, 6);
+
+        let mut members = set.members(6).collect::<Vec<_>>();
+        members.sort_unstable();
+        assert_eq!(members, [1, 2, 6]);
+        assert_eq!(set.members(3).collect::<Vec<_>>(), [3]);
+
+        assert_eq!(set.largest().map(|root| set.size(root)), Some(3));
+        assert!(set.same(set.largest().unwrap(), 1));
+        assert_eq!(DisjointSet::new(0).largest(), None);
+    }
+}
@@ -0,0 +1,47 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+use everybody_codes::parts_data::{Error, PartsData};
+
+// Generates `REGISTRY` from the quest crates among the dependencies, the ones
+// with a `[package.metadata.everybody_codes]` table.
+fn main() {
+    println!("cargo::rerun-if-changed=Cargo.toml");
+
+    if let Err(err) = generate() {
+        println!("cargo::error={err}");
+    }
+}
+
+fn generate() -> Result<(), String> {
+    let manifest = fs::read_to_string("Cargo.toml")
+        .map_err(|err| format!("Cargo.toml: {err}"))?
+        .parse::<toml::Table>()
+        .map_err(|err| format!("Cargo.toml: {err}"))?;
+
+    let mut quests = vec![];
+    for (name, dependency) in manifest
+        .get("dependencies")
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flatten()
+    {
+        let Some(path) = dependency.get("path").and_then(toml::Value::as_str) else {
+            continue;
+        };
+        match PartsData::new_from_manifest(&Path::new(path).join("Cargo.toml"), "data") {
+            Ok(data) => quests.push((data.puzzle(), name.replace('-', "_"))),
+            Err(Error::PackageMetadataMissing { .. }) => {}
+            Err(err) => return Err(everybody_codes::Report(err).to_string()),
+        }
+    }
+    quests.sort_unstable();
+
+    let mut code = "pub const REGISTRY: &[Entry] = &[\n".to_string();
+    for (_, ident) in quests {
+        writeln!(code, "    {ident}::ENTRY,").expect("writing to a String");
+    }
+    code.push_str("];\n");
+
+    let out = Path::new(&env::var_os("OUT_DIR").ok_or("OUT_DIR not set")?).join("registry.rs");
+    fs::write(&out, code).map_err(|err| format!("{}: {err}", out.display()))
+}
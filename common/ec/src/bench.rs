@@ -0,0 +1,336 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+use serde::Deserialize;
+
+use everybody_codes::PuzzleId;
+
+/// The criterion estimates of a benchmark: the median in nanoseconds, the
+/// variance in squared nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimates {
+    pub median: f64,
+    pub variance: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub puzzle: PuzzleId,
+    pub bench: String,
+    pub estimates: Estimates,
+    pub baseline: Option<Estimates>,
+}
+
+#[derive(Deserialize)]
+struct Benchmark {
+    function_id: String,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+#[derive(Deserialize)]
+struct RawEstimates {
+    median: Estimate,
+    std_dev: Estimate,
+}
+
+impl Row {
+    /// The relative change of the median against the baseline, in percent.
+    #[must_use]
+    pub fn change(&self) -> Option<f64> {
+        self.baseline
+            .map(|baseline| (self.estimates.median / baseline.median - 1.0) * 100.0)
+    }
+
+    #[must_use]
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// The directory where criterion stores its results.
+#[must_use]
+pub fn criterion_dir() -> PathBuf {
+    env::var_os("CRITERION_HOME").map_or_else(
+        || {
+            env::var_os("CARGO_TARGET_DIR")
+                .map_or_else(|| crate::workspace_dir().join("target"), PathBuf::from)
+                .join("criterion")
+        },
+        PathBuf::from,
+    )
+}
+
+/// Runs the benchmarks of `puzzle`, saving them as criterion baseline
+/// `save_baseline` if given.
+///
+/// # Errors
+pub fn run(puzzle: PuzzleId, save_baseline: Option<&str>) -> Result<(), String> {
+    let mut command = process::Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(crate::workspace_dir()).args([
+        "bench",
+        "--package",
+        &puzzle.slug(),
+        "--bench",
+        "bench",
+    ]);
+    if let Some(name) = save_baseline {
+        command.args(["--", "--save-baseline", name]);
+    }
+
+    let status = command
+        .status()
+        .map_err(|err| format!("cannot run cargo bench: {err}"))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{puzzle}: cargo bench failed ({status})"))
+    }
+}
+
+/// Collects the latest results of `puzzle` from `dir`, along with the ones of
+/// criterion baseline `baseline` if given.
+///
+/// # Errors
+pub fn collect(dir: &Path, puzzle: PuzzleId, baseline: Option<&str>) -> io::Result<Vec<Row>> {
+    let mut benches = vec![];
+    find(&dir.join(puzzle.slug()), &mut benches)?;
+
+    let mut rows = benches
+        .into_iter()
+        .map(|bench| {
+            let function: Benchmark = read(&bench.join("new/benchmark.json"))?;
+            let baseline = baseline
+                .map(|name| bench.join(name).join("estimates.json"))
+                .filter(|path| path.is_file())
+                .map(|path| estimates(&path))
+                .transpose()?;
+
+            Ok(Row {
+                puzzle,
+                bench: function.function_id,
+                estimates: estimates(&bench.join("new/estimates.json"))?,
+                baseline,
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    rows.sort_by(|a, b| a.bench.cmp(&b.bench));
+
+    Ok(rows)
+}
+
+fn find(dir: &Path, benches: &mut Vec<PathBuf>) -> io::Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries {
+        let path = entry?.path();
+        if path.join("new/benchmark.json").is_file() {
+            benches.push(path);
+        } else if path.is_dir() && path.file_name().is_some_and(|name| name != "report") {
+            find(&path, benches)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read<T: serde::de::DeserializeOwned>(path: &Path) -> io::Result<T> {
+    serde_json::from_slice(&fs::read(path)?).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {err}", path.display()),
+        )
+    })
+}
+
+fn estimates(path: &Path) -> io::Result<Estimates> {
+    let RawEstimates { median, std_dev } = read(path)?;
+
+    Ok(Estimates {
+        median: median.point_estimate,
+        variance: std_dev.point_estimate.powi(2),
+    })
+}
+
+/// Formats a duration in nanoseconds the way criterion does.
+#[must_use]
+pub fn time(ns: f64) -> String {
+    if ns < 1e3 {
+        format!("{ns:.2} ns")
+    } else if ns < 1e6 {
+        format!("{:.2} µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.2} ms", ns / 1e6)
+    } else {
+        format!("{:.2} s", ns / 1e9)
+    }
+}
+
+/// Formats a variance in squared nanoseconds, in the unit [`time`] picks for
+/// its standard deviation.
+#[must_use]
+pub fn variance(ns2: f64) -> String {
+    let (scale, unit) = match ns2.sqrt() {
+        std_dev if std_dev < 1e3 => (1.0, "ns²"),
+        std_dev if std_dev < 1e6 => (1e3, "µs²"),
+        std_dev if std_dev < 1e9 => (1e6, "ms²"),
+        _ => (1e9, "s²"),
+    };
+
+    format!("{:.2} {unit}", ns2 / (scale * scale))
+}
+
+/// Prints `rows` as a table, returning the number of regressions above
+/// `threshold` percent.
+#[must_use]
+pub fn report(rows: &[Row], threshold: f64) -> usize {
+    let compare = rows.iter().any(|row| row.baseline.is_some());
+
+    let mut lines = vec![["quest", "part", "median", "variance", "change", ""].map(str::to_string)];
+    for row in rows {
+        let change = row.change();
+        lines.push([
+            row.puzzle.slug(),
+            row.bench.clone(),
+            time(row.estimates.median),
+            variance(row.estimates.variance),
+            change.map_or_else(|| "-".to_string(), |change| format!("{change:+.2}%")),
+            if row.regressed(threshold) {
+                "REGRESSION".to_string()
+            } else {
+                String::new()
+            },
+        ]);
+    }
+
+    let columns = if compare { 6 } else { 4 };
+    let widths = (0..columns)
+        .map(|column| {
+            lines
+                .iter()
+                .map(|line| line[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    for line in &lines {
+        let line = line
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, &width))| {
+                if column < 2 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+
+    rows.iter().filter(|row| row.regressed(threshold)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: PuzzleId = PuzzleId::Event {
+        year: 2025,
+        quest: 1,
+    };
+
+    fn write(dir: &Path, function: &str, baseline: &str, median: f64) {
+        let dir = dir.join(baseline);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("benchmark.json"),
+            serde_json::json!({ "function_id": function }).to_string(),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("estimates.json"),
+            serde_json::json!({
+                "median": { "point_estimate": median },
+                "std_dev": { "point_estimate": median / 10.0 },
+            })
+            .to_string(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_collect() {
+        let dir = env::temp_dir().join(format!("ec-bench-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let group = dir.join(PUZZLE.slug());
+        write(&group.join("part 1"), "part 1", "new", 110.0);
+        write(&group.join("part 1"), "before", "before", 100.0);
+        write(&group.join("part 3/par"), "part 3/par", "new", 1_000.0);
+        fs::create_dir_all(group.join("report")).unwrap();
+
+        let rows = collect(&dir, PUZZLE, Some("before")).unwrap();
+
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.bench.as_str())
+                .collect::<Vec<_>>(),
+            ["part 1", "part 3/par"]
+        );
+        assert_eq!(
+            rows[0].estimates,
+            Estimates {
+                median: 110.0,
+                variance: 121.0
+            }
+        );
+        assert!(rows[0].regressed(5.0));
+        assert!(!rows[0].regressed(15.0));
+        assert_eq!(rows[1].change(), None);
+
+        assert!(
+            collect(
+                &dir,
+                PuzzleId::Story {
+                    number: 1,
+                    quest: 1
+                },
+                None
+            )
+            .unwrap()
+            .is_empty()
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_time() {
+        assert_eq!(time(217.887), "217.89 ns");
+        assert_eq!(time(1_500.0), "1.50 µs");
+        assert_eq!(time(2_250_000.0), "2.25 ms");
+        assert_eq!(time(3e9), "3.00 s");
+    }
+
+    #[test]
+    fn test_variance() {
+        assert_eq!(variance(121.0), "121.00 ns²");
+        assert_eq!(variance(2.25e6), "2.25 µs²");
+        assert_eq!(variance(4e12), "4.00 ms²");
+        assert_eq!(variance(9e18), "9.00 s²");
+    }
+}
@@ -0,0 +1,587 @@
+use std::{
+    fs, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Instant, UNIX_EPOCH},
+};
+
+use clap::{Args as ClapArgs, Parser, Subcommand};
+
+use everybody_codes::{
+    PuzzleId, Report,
+    cache::{Cache, PartState},
+    client::{AnswerOutcome, Client},
+    ledger::Ledger,
+    notes, samples,
+};
+use everybody_codes_quest::registry::Entry;
+
+mod bench;
+mod registry;
+mod scaffold;
+
+/// Everybody Codes quest runner
+#[derive(Parser)]
+#[command(version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(ClapArgs)]
+struct Puzzle {
+    /// `<YEAR> [QUEST]` or `story <NUMBER> [QUEST]`
+    #[arg(required_unless_present = "all")]
+    puzzle: Vec<String>,
+
+    /// Select all quests
+    #[arg(long, conflicts_with = "puzzle")]
+    all: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run quests on their input data
+    Run {
+        #[command(flatten)]
+        puzzle: Puzzle,
+
+        /// Run only this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=3))]
+        part: Option<u8>,
+
+        /// Check the samples in `tests/samples` instead
+        #[arg(long)]
+        samples: bool,
+    },
+    /// Download missing input notes into the cache
+    Fetch {
+        #[command(flatten)]
+        puzzle: Puzzle,
+    },
+    /// Download input notes into the cache, even if already present
+    Refresh {
+        #[command(flatten)]
+        puzzle: Puzzle,
+    },
+    /// Submit an answer, recording it in the cache when correct
+    Submit {
+        /// `<YEAR> <QUEST>` or `story <NUMBER> <QUEST>`
+        #[arg(required = true)]
+        puzzle: Vec<String>,
+
+        /// Part to submit
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=3))]
+        part: u8,
+
+        /// Answer to submit, computed by the quest solver if missing
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+    /// Create the crate of a new quest and register it in the workspace
+    New {
+        /// `<YEAR> <QUEST>` or `story <NUMBER> <QUEST>`
+        #[arg(required = true)]
+        puzzle: Vec<String>,
+
+        /// Download the input notes into the cache
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Show the progress of quests
+    Status {
+        /// `<YEAR> [QUEST]` or `story <NUMBER> [QUEST]`
+        puzzle: Vec<String>,
+    },
+    /// Remove input notes from the cache
+    Clear {
+        #[command(flatten)]
+        puzzle: Puzzle,
+    },
+    /// Check the session and show the user profile
+    Me,
+    /// Benchmark quests and report criterion estimates
+    Bench {
+        /// `<YEAR> [QUEST]` or `story <NUMBER> [QUEST]`
+        puzzle: Vec<String>,
+
+        /// Report the last results without running the benchmarks
+        #[arg(long)]
+        no_run: bool,
+
+        /// Save the results as a criterion baseline
+        #[arg(long, value_name = "NAME")]
+        save_baseline: Option<String>,
+
+        /// Compare the results with a saved criterion baseline
+        #[arg(long, value_name = "NAME")]
+        baseline: Option<String>,
+
+        /// Median slowdown, in percent, reported as a regression
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+    },
+    /// Encrypt a data directory into the input notes format of the server
+    Fixture {
+        /// Directory with `part_N` and optional `answer_N` files
+        data_dir: PathBuf,
+
+        /// Directory to write `input.json` and `keys.json` into
+        out_dir: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Selector {
+    All,
+    Year(u16),
+    Story(u8),
+    Quest(PuzzleId),
+}
+
+impl Selector {
+    fn parse(puzzle: &[String]) -> Result<Self, String> {
+        let puzzle = puzzle.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let (story, event, quest) = match puzzle.as_slice() {
+            [] => return Ok(Self::All),
+            ["story", number] => (true, *number, None),
+            ["story", number, quest] => (true, *number, Some(*quest)),
+            [year] => (false, *year, None),
+            [year, quest] => (false, *year, Some(*quest)),
+            _ => return Err(format!("invalid puzzle: {}", puzzle.join(" "))),
+        };
+
+        let quest = quest
+            .map(|quest| {
+                quest
+                    .parse::<u8>()
+                    .map_err(|_| format!("invalid quest: {quest}"))
+            })
+            .transpose()?;
+
+        if story {
+            let number = event
+                .parse()
+                .map_err(|_| format!("invalid story: {event}"))?;
+            Ok(match quest {
+                Some(quest) => Self::Quest(PuzzleId::Story { number, quest }),
+                None => Self::Story(number),
+            })
+        } else {
+            let year = event
+                .parse()
+                .map_err(|_| format!("invalid event: {event}"))?;
+            Ok(match quest {
+                Some(quest) => Self::Quest(PuzzleId::Event { year, quest }),
+                None => Self::Year(year),
+            })
+        }
+    }
+
+    fn matches(self, puzzle: PuzzleId) -> bool {
+        match (self, puzzle) {
+            (Self::All, _) => true,
+            (Self::Year(selected), PuzzleId::Event { year, .. }) => year == selected,
+            (Self::Story(selected), PuzzleId::Story { number, .. }) => number == selected,
+            (Self::Quest(selected), puzzle) => puzzle == selected,
+            _ => false,
+        }
+    }
+
+    fn targets(self) -> Vec<(PuzzleId, Vec<u8>)> {
+        let mut targets = registry::REGISTRY
+            .iter()
+            .filter(|entry| self.matches(entry.puzzle))
+            .map(|entry| {
+                (
+                    entry.puzzle,
+                    (1..=u8::try_from(entry.parts.len()).expect("too many parts")).collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if let Self::Quest(puzzle) = self
+            && targets.is_empty()
+        {
+            targets.push((puzzle, vec![1, 2, 3]));
+        }
+
+        targets
+    }
+}
+
+fn run(entry: &Entry, part: Option<u8>) {
+    println!("{}", entry.puzzle);
+
+    for (index, solver) in entry.parts.iter().enumerate() {
+        let current = u8::try_from(index + 1).expect("too many parts");
+        if part.is_some_and(|part| part != current) {
+            continue;
+        }
+
+        let path = format!("{}/part_{current}", entry.data_dir);
+        let data = match fs::read_to_string(&path) {
+            Ok(data) if !data.trim().is_empty() => data,
+            _ => {
+                println!("  part {current}: missing input {path}");
+                continue;
+            }
+        };
+
+        let start = Instant::now();
+        let result = panic::catch_unwind(|| solver(&data));
+        let elapsed = start.elapsed();
+
+        match result {
+            Ok(answer) => println!("  part {current}: {answer} ({elapsed:?})"),
+            Err(_) => println!("  part {current}: failed ({elapsed:?})"),
+        }
+    }
+}
+
+fn run_samples(entry: &Entry, part: Option<u8>) {
+    println!("{}", entry.puzzle);
+
+    let dir = Path::new(entry.data_dir).with_file_name("tests/samples");
+    let samples = match samples::find(&dir) {
+        Ok(samples) => samples,
+        Err(err) => {
+            println!("  {}", Report(err));
+            return;
+        }
+    };
+
+    for sample in samples
+        .iter()
+        .filter(|sample| part.is_none_or(|part| part == sample.part))
+    {
+        let Some(solver) = entry.parts.get(usize::from(sample.part - 1)) else {
+            continue;
+        };
+        if let Some(reason) = &sample.ignore {
+            println!("  {}: ignored ({reason})", sample.name());
+            continue;
+        }
+        let (input, expected) = match (sample.read_input(), sample.read_answer()) {
+            (Ok(input), Ok(expected)) => (input, expected),
+            (Err(err), _) | (_, Err(err)) => {
+                println!("  {}: {}", sample.name(), Report(err));
+                continue;
+            }
+        };
+
+        match panic::catch_unwind(|| solver(&input)) {
+            Ok(answer) if answer.trim() == expected => println!("  {}: {answer} ok", sample.name()),
+            Ok(answer) => println!("  {}: {answer}, expected {expected}", sample.name()),
+            Err(_) => println!("  {}: failed", sample.name()),
+        }
+    }
+}
+
+fn cached(command: &Command, puzzle: &[String]) -> Result<(), String> {
+    let selector = Selector::parse(puzzle)?;
+
+    let client = Client::new_from_config_or_profile().map_err(|err| Report(err).to_string())?;
+    let cache = Cache::new_default().map_err(|err| Report(err).to_string())?;
+
+    let targets = selector.targets();
+    if targets.is_empty() {
+        return Err("no quest found".to_string());
+    }
+
+    let mut progress = vec![];
+    for (puzzle, parts) in targets {
+        let result = match command {
+            Command::Fetch { .. } => cache
+                .fetch(&client, puzzle, &parts)
+                .map(|modified| if modified { "fetched" } else { "up to date" }.to_string())
+                .map_err(|err| Report(err).to_string()),
+            Command::Refresh { .. } => cache
+                .refresh(&client, puzzle)
+                .map(|modified| if modified { "updated" } else { "up to date" }.to_string())
+                .map_err(|err| Report(err).to_string()),
+            Command::Clear { .. } => cache
+                .clear(puzzle, client.seed())
+                .map(|removed| if removed { "cleared" } else { "not cached" }.to_string())
+                .map_err(|err| Report(err).to_string()),
+            Command::Status { .. } => cache
+                .status(puzzle, client.seed(), &parts)
+                .map(|status| {
+                    progress.extend(status.iter().map(|status| status.state));
+                    status
+                        .iter()
+                        .map(|status| format!("part {}: {}", status.part, state(status.state)))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                })
+                .map_err(|err| Report(err).to_string()),
+            Command::Run { .. }
+            | Command::Submit { .. }
+            | Command::New { .. }
+            | Command::Me
+            | Command::Bench { .. }
+            | Command::Fixture { .. } => unreachable!(),
+        };
+
+        match result {
+            Ok(message) => println!("{puzzle}: {message}"),
+            Err(err) => println!("{puzzle}: {err}"),
+        }
+    }
+
+    if !progress.is_empty() {
+        let count = |expected| progress.iter().filter(|&&state| state == expected).count();
+        println!(
+            "{}/{} parts solved, {} fetched, {} locked, {} unlocked, {} missing",
+            count(Some(PartState::Solved)),
+            progress.len(),
+            count(Some(PartState::Fetched)),
+            count(Some(PartState::Locked)),
+            count(Some(PartState::Unlocked)),
+            count(None),
+        );
+    }
+
+    Ok(())
+}
+
+fn state(state: Option<PartState>) -> &'static str {
+    match state {
+        Some(PartState::Solved) => "solved",
+        Some(PartState::Fetched) => "fetched",
+        Some(PartState::Unlocked) => "unlocked",
+        Some(PartState::Locked) => "locked",
+        None => "missing",
+    }
+}
+
+fn submit(puzzle: &[String], part: u8, answer: Option<&str>) -> Result<(), String> {
+    let Selector::Quest(puzzle) = Selector::parse(puzzle)? else {
+        return Err("select a single quest".to_string());
+    };
+
+    let answer = if let Some(answer) = answer {
+        answer.to_string()
+    } else {
+        let entry = registry::REGISTRY
+            .iter()
+            .find(|entry| entry.puzzle == puzzle)
+            .ok_or("no quest found")?;
+        let solver = entry
+            .parts
+            .get(usize::from(part - 1))
+            .ok_or(format!("part {part} not available"))?;
+        let path = format!("{}/part_{part}", entry.data_dir);
+        let data = fs::read_to_string(&path).map_err(|err| format!("{path}: {err}"))?;
+        solver(&data)
+    };
+
+    let client = Client::new_from_config_or_profile().map_err(|err| Report(err).to_string())?;
+    let cache = Cache::new_default().map_err(|err| Report(err).to_string())?;
+
+    let message = match client
+        .submit_answer(puzzle, part, &answer)
+        .map_err(|err| Report(err).to_string())?
+    {
+        AnswerOutcome::Correct => {
+            cache
+                .solve(puzzle, client.seed(), part, &answer)
+                .map_err(|err| err.to_string())?;
+            "correct".to_string()
+        }
+        AnswerOutcome::Wrong => "wrong".to_string(),
+        AnswerOutcome::WrongLength => "wrong length".to_string(),
+        AnswerOutcome::WrongFirstChar => "wrong first character".to_string(),
+        AnswerOutcome::RateLimited {
+            retry_after: Some(retry_after),
+        } => format!("rate limited, retry in {}s", retry_after.as_secs()),
+        AnswerOutcome::RateLimited { retry_after: None } => "rate limited".to_string(),
+    };
+    println!("{puzzle} part {part}: {answer} is {message}");
+
+    Ok(())
+}
+
+fn new(puzzle: &[String], fetch: bool) -> Result<(), String> {
+    let Selector::Quest(puzzle) = Selector::parse(puzzle)? else {
+        return Err("select a single quest".to_string());
+    };
+
+    for path in scaffold::create(&workspace_dir(), puzzle)? {
+        println!("{}", path.display());
+    }
+
+    if fetch {
+        let client = Client::new_from_config_or_profile().map_err(|err| Report(err).to_string())?;
+        let cache = Cache::new_default().map_err(|err| Report(err).to_string())?;
+        cache
+            .fetch(&client, puzzle, &[1, 2, 3])
+            .map_err(|err| Report(err).to_string())?;
+        println!("{puzzle}: fetched");
+    }
+
+    Ok(())
+}
+
+fn me() -> Result<(), String> {
+    let client = Client::new_from_config_or_profile().map_err(|err| Report(err).to_string())?;
+    let profile = client.me().map_err(|err| Report(err).to_string())?;
+
+    println!("name: {}", profile.name);
+    println!("seed: {}", profile.seed);
+    if let Ok(server_time) = profile.server_time.duration_since(UNIX_EPOCH) {
+        println!("server time: {}", server_time.as_secs());
+    }
+
+    Ok(())
+}
+
+fn bench(
+    puzzle: &[String],
+    no_run: bool,
+    save_baseline: Option<&str>,
+    baseline: Option<&str>,
+    threshold: f64,
+) -> Result<(), String> {
+    let selector = Selector::parse(puzzle)?;
+
+    let puzzles = registry::REGISTRY
+        .iter()
+        .map(|entry| entry.puzzle)
+        .filter(|&puzzle| selector.matches(puzzle))
+        .collect::<Vec<_>>();
+    if puzzles.is_empty() {
+        return Err("no quest found".to_string());
+    }
+
+    if !no_run {
+        for &puzzle in &puzzles {
+            bench::run(puzzle, save_baseline)?;
+        }
+    }
+
+    let dir = bench::criterion_dir();
+    let mut rows = vec![];
+    for puzzle in puzzles {
+        rows.extend(
+            bench::collect(&dir, puzzle, baseline).map_err(|err| format!("{puzzle}: {err}"))?,
+        );
+    }
+    if rows.is_empty() {
+        return Err(format!("no benchmark results in {}", dir.display()));
+    }
+
+    match bench::report(&rows, threshold) {
+        0 => Ok(()),
+        regressions => Err(format!(
+            "{regressions} benchmarks regressed by more than {threshold}%"
+        )),
+    }
+}
+
+fn fixture(data_dir: &Path, out_dir: &Path) -> Result<(), String> {
+    let ledger = Ledger::new(data_dir.to_str().ok_or("invalid data directory")?);
+
+    let mut parts = vec![];
+    for part in 1..=3 {
+        let path = data_dir.join(format!("part_{part}"));
+        // Locked parts are blank placeholders, and so are the ones after.
+        let data = match fs::read_to_string(&path) {
+            Ok(data) if !data.trim().is_empty() => data,
+            _ => break,
+        };
+        let answer = ledger.answer(part).map_err(|err| err.to_string())?;
+        parts.push((data, answer));
+    }
+    if parts.is_empty() {
+        return Err(format!("no part_N files in {}", data_dir.display()));
+    }
+
+    let sealed = notes::seal(
+        &parts
+            .iter()
+            .map(|(data, answer)| notes::Part {
+                data,
+                answer: answer.as_deref(),
+            })
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|err| Report(err).to_string())?;
+
+    fs::create_dir_all(out_dir).map_err(|err| err.to_string())?;
+    for (name, value) in [("input.json", sealed.input), ("keys.json", sealed.keys)] {
+        let path = out_dir.join(name);
+        fs::write(&path, value.to_string()).map_err(|err| format!("{}: {err}", path.display()))?;
+        println!("{}", path.display());
+    }
+
+    Ok(())
+}
+
+fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let result = match &args.command {
+        Command::Run {
+            puzzle,
+            part,
+            samples,
+        } => Selector::parse(&puzzle.puzzle).and_then(|selector| {
+            let mut found = false;
+            for entry in registry::REGISTRY
+                .iter()
+                .filter(|entry| selector.matches(entry.puzzle))
+            {
+                found = true;
+                if *samples {
+                    run_samples(entry, *part);
+                } else {
+                    run(entry, *part);
+                }
+            }
+
+            if found {
+                Ok(())
+            } else {
+                Err("no quest found".to_string())
+            }
+        }),
+        Command::Fetch { puzzle } | Command::Refresh { puzzle } | Command::Clear { puzzle } => {
+            cached(&args.command, &puzzle.puzzle)
+        }
+        Command::Status { puzzle } => cached(&args.command, puzzle),
+        Command::Submit {
+            puzzle,
+            part,
+            answer,
+        } => submit(puzzle, *part, answer.as_deref()),
+        Command::New { puzzle, fetch } => new(puzzle, *fetch),
+        Command::Me => me(),
+        Command::Bench {
+            puzzle,
+            no_run,
+            save_baseline,
+            baseline,
+            threshold,
+        } => bench(
+            puzzle,
+            *no_run,
+            save_baseline.as_deref(),
+            baseline.as_deref(),
+            *threshold,
+        ),
+        Command::Fixture { data_dir, out_dir } => fixture(data_dir, out_dir),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,3 @@
+use everybody_codes_quest::registry::Entry;
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
@@ -0,0 +1,265 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use everybody_codes::PuzzleId;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const BUILD_RS: &str = include_str!("../templates/build.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const BENCH_RS: &str = include_str!("../templates/bench.rs.tmpl");
+const GITIGNORE: &str = include_str!("../templates/gitignore.tmpl");
+const SAMPLE_ANSWER: &str = "0\n";
+const SAMPLE_IGNORE: &str = "missing sample\n";
+
+/// The crate directory of `puzzle`, relative to the workspace.
+#[must_use]
+pub fn crate_path(puzzle: PuzzleId) -> String {
+    match puzzle {
+        PuzzleId::Event { year, quest } => format!("events/{year}/quest-{quest:02}"),
+        PuzzleId::Story { number, quest } => format!("stories/{number}/quest-{quest:02}"),
+    }
+}
+
+/// Creates the crate of `puzzle` in `workspace` and registers it in the
+/// workspace members and as a dependency of the runner, whose build script
+/// adds it to the registry. Returns the created files relative to
+/// `workspace`.
+///
+/// # Errors
+pub fn create(workspace: &Path, puzzle: PuzzleId) -> Result<Vec<PathBuf>, String> {
+    let path = crate_path(puzzle);
+    let dir = workspace.join(&path);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let name = puzzle.slug();
+    let ident = name.replace('-', "_");
+    let (metadata, id) = match puzzle {
+        PuzzleId::Event { year, quest } => (
+            format!("event = {year}\nquest = {quest}"),
+            format!(
+                "everybody_codes_quest::PuzzleId::Event {{\n        year: {year},\n        quest: {quest},\n    }}"
+            ),
+        ),
+        PuzzleId::Story { number, quest } => (
+            format!("kind = \"story\"\nevent = {number}\nquest = {quest}"),
+            format!(
+                "everybody_codes_quest::PuzzleId::Story {{\n        number: {number},\n        quest: {quest},\n    }}"
+            ),
+        ),
+    };
+    let render = |template: &str| {
+        template
+            .replace("{name}", &name)
+            .replace("{ident}", &ident)
+            .replace("{metadata}", &metadata)
+            .replace("{id}", &id)
+    };
+
+    let workspace_manifest = workspace.join("Cargo.toml");
+    let runner_manifest = workspace.join("common/ec/Cargo.toml");
+
+    let members = add_member(&read(&workspace_manifest)?, puzzle, &path)?;
+    let dependencies = insert_sorted(
+        &read(&runner_manifest)?,
+        dependency,
+        &name,
+        &format!("{name} = {{ path = \"../../{path}\" }}"),
+    )?;
+
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("build.rs", BUILD_RS),
+        ("src/main.rs", MAIN_RS),
+        ("src/lib.rs", LIB_RS),
+        ("benches/bench.rs", BENCH_RS),
+        ("data/.gitignore", GITIGNORE),
+        // Empty inputs, like the ones of locked parts, so the crate builds
+        // before its input notes are fetched.
+        ("data/part_1", ""),
+        ("data/part_2", ""),
+        ("data/part_3", ""),
+        ("tests/samples/part_1_1.txt", ""),
+        ("tests/samples/part_1_1.answer", SAMPLE_ANSWER),
+        ("tests/samples/part_1_1.ignore", SAMPLE_IGNORE),
+        ("tests/samples/part_2_1.txt", ""),
+        ("tests/samples/part_2_1.answer", SAMPLE_ANSWER),
+        ("tests/samples/part_2_1.ignore", SAMPLE_IGNORE),
+        ("tests/samples/part_3_1.txt", ""),
+        ("tests/samples/part_3_1.answer", SAMPLE_ANSWER),
+        ("tests/samples/part_3_1.ignore", SAMPLE_IGNORE),
+    ]
+    .into_iter()
+    .map(|(file, template)| {
+        write(&dir.join(file), &render(template))?;
+        Ok(Path::new(&path).join(file))
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+
+    write(&workspace_manifest, &members)?;
+    write(&runner_manifest, &dependencies)?;
+
+    Ok(files)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    }
+    fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Adds `path` to the workspace members, next to the quests of the same
+/// event or story, or in a new group at the end.
+fn add_member(manifest: &str, puzzle: PuzzleId, path: &str) -> Result<String, String> {
+    let prefix = &path[..=path.rfind('/').unwrap_or_default()];
+    let member = format!("    \"{path}\",");
+    if manifest
+        .lines()
+        .any(|line| grouped_member(line, prefix).is_some())
+    {
+        return insert_sorted(manifest, |line| grouped_member(line, prefix), path, &member);
+    }
+
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))
+        .ok_or("no workspace members")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("no workspace members")?;
+
+    let group = match puzzle {
+        PuzzleId::Event { year, .. } => format!("    # Event {year}"),
+        PuzzleId::Story { number, .. } => format!("    # Story {number}"),
+    };
+    lines.splice(end..end, ["", &group, &member]);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn dependency(line: &str) -> Option<&str> {
+    let (name, _) = line.split_once(" = { path = ")?;
+    (name.starts_with("event-") || name.starts_with("story-")).then_some(name)
+}
+
+fn grouped_member<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    let member = line.trim().strip_prefix('"')?.strip_suffix("\",")?;
+    member.starts_with(prefix).then_some(member)
+}
+
+/// Inserts `line` among the lines with a key, keeping them sorted by key.
+fn insert_sorted(
+    text: &str,
+    key: impl Fn(&str) -> Option<&str>,
+    new: &str,
+    line: &str,
+) -> Result<String, String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+
+    let keys = lines
+        .iter()
+        .copied()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, key(line)?)))
+        .collect::<Vec<_>>();
+    if keys.iter().any(|&(_, key)| key == new) {
+        return Err(format!("{new} is already registered"));
+    }
+
+    let index = keys
+        .iter()
+        .find(|&&(_, key)| key > new)
+        .map(|&(index, _)| index)
+        .or_else(|| keys.last().map(|&(index, _)| index + 1))
+        .ok_or(format!("no place to register {new}"))?;
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMBERS: &str = r#"[workspace]
+members = [
+    "common/ec",
+
+    # Event 2025
+    "events/2025/quest-01",
+    "events/2025/quest-03",
+]
+"#;
+
+    #[test]
+    fn test_add_member() {
+        let puzzle = PuzzleId::Event {
+            year: 2025,
+            quest: 2,
+        };
+        assert_eq!(
+            add_member(MEMBERS, puzzle, &crate_path(puzzle)).unwrap(),
+            MEMBERS.replace(
+                "quest-01\",\n",
+                "quest-01\",\n    \"events/2025/quest-02\",\n"
+            )
+        );
+
+        let puzzle = PuzzleId::Story {
+            number: 4,
+            quest: 1,
+        };
+        assert_eq!(
+            add_member(MEMBERS, puzzle, &crate_path(puzzle)).unwrap(),
+            MEMBERS.replace(
+                "quest-03\",\n",
+                "quest-03\",\n\n    # Story 4\n    \"stories/4/quest-01\",\n"
+            )
+        );
+
+        assert!(add_member(MEMBERS, puzzle, "events/2025/quest-03").is_err());
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let manifest = "[dependencies]\nclap.workspace = true\n\nevent-2025-20 = { path = \"../../events/2025/quest-20\" }\nstory-1-01 = { path = \"../../stories/1/quest-01\" }\n";
+        let line = |name: &str, path: &str| format!("{name} = {{ path = \"../../{path}\" }}");
+
+        assert_eq!(
+            insert_sorted(
+                manifest,
+                dependency,
+                "event-2025-21",
+                &line("event-2025-21", "events/2025/quest-21")
+            )
+            .unwrap(),
+            manifest.replace(
+                "story-1-01",
+                "event-2025-21 = { path = \"../../events/2025/quest-21\" }\nstory-1-01"
+            )
+        );
+        assert_eq!(
+            insert_sorted(
+                manifest,
+                dependency,
+                "story-4-01",
+                &line("story-4-01", "stories/4/quest-01")
+            )
+            .unwrap(),
+            format!("{manifest}story-4-01 = {{ path = \"../../stories/4/quest-01\" }}\n")
+        );
+        assert!(insert_sorted(manifest, dependency, "story-1-01", "").is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use std::{fs, hint};
+
+pub use criterion;
+
+use criterion::{BenchmarkGroup, Criterion, measurement::WallTime};
+use everybody_codes_quest::Quest;
+
+/// The input data of a quest, read at startup so that missing parts can be
+/// skipped instead of failing the build.
+#[derive(Debug, Clone)]
+pub struct Data {
+    parts: [Option<String>; 3],
+}
+
+impl Data {
+    #[must_use]
+    pub fn load(data_dir: &str) -> Self {
+        Self {
+            parts: [1, 2, 3].map(|part| fs::read_to_string(format!("{data_dir}/part_{part}")).ok()),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the part is missing.
+    #[must_use]
+    pub fn part(&self, part: u8) -> &str {
+        self.get(part)
+            .unwrap_or_else(|| panic!("missing input for part {part}"))
+    }
+}
+
+/// Creates the benchmark group of `Q`, named after its package.
+pub fn group<Q: Quest>(c: &mut Criterion) -> BenchmarkGroup<'_, WallTime> {
+    c.benchmark_group(Q::ID.slug())
+}
+
+/// Benches the first `parts` parts of `Q` as `part N`, skipping the ones
+/// without input.
+pub fn parts<Q: Quest>(group: &mut BenchmarkGroup<'_, WallTime>, data: &Data, parts: u8) {
+    for part in 1..=parts.min(Q::PARTS) {
+        let Some(input) = data.get(part) else {
+            eprintln!("{}: skipping part {part}, no input", Q::ID);
+            continue;
+        };
+
+        let name = format!("part {part}");
+        match part {
+            1 => group.bench_function(name, |b| {
+                b.iter(|| hint::black_box(Q::part_1(hint::black_box(input))));
+            }),
+            2 => group.bench_function(name, |b| {
+                b.iter(|| hint::black_box(Q::part_2(hint::black_box(input))));
+            }),
+            _ => group.bench_function(name, |b| {
+                b.iter(|| hint::black_box(Q::part_3(hint::black_box(input))));
+            }),
+        };
+    }
+}
+
+/// Defines the criterion entry point of a quest bench.
+///
+/// `bench!(Solution)` benches every part of the quest. Extra benchmarks are
+/// added to the same group with a closure-like block, and `parts = N` limits
+/// the standard parts so that slow ones can be configured in the block:
+///
+/// ```ignore
+/// everybody_codes_bench::bench!(event::Solution, parts = 2, |group, data| {
+///     group.sample_size(10);
+///     group.bench_function("part 3", |b| b.iter(|| event::part_3(data.part(3))));
+/// });
+/// ```
+#[macro_export]
+macro_rules! bench {
+    ($quest:ty) => {
+        $crate::bench!($quest, |group, data| {});
+    };
+    ($quest:ty, |$group:ident, $data:ident| $body:block) => {
+        $crate::bench!($quest, parts = 3, |$group, $data| $body);
+    };
+    ($quest:ty, parts = $parts:expr, |$group:ident, $data:ident| $body:block) => {
+        fn criterion_benchmark(c: &mut $crate::criterion::Criterion) {
+            let $data = $crate::Data::load(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+            let mut $group = $crate::group::<$quest>(c);
+            $crate::parts::<$quest>(&mut $group, &$data, $parts);
+            $body
+            $group.finish();
+        }
+
+        $crate::criterion::criterion_group!(benches, criterion_benchmark);
+        $crate::criterion::criterion_main!(benches);
+    };
+}
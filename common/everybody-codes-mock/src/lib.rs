@@ -0,0 +1,417 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+};
+
+use everybody_codes::notes::{KEY_LEN, encrypt};
+
+#[derive(Debug, Clone)]
+pub struct Part {
+    pub data: String,
+    pub answer: String,
+    pub key: String,
+}
+
+/// The input notes of a quest, in the shape served by everybody.codes.
+#[derive(Debug, Clone)]
+pub struct Quest {
+    assets_path: String,
+    api_path: String,
+    parts: Vec<Part>,
+    unlocked: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// A canned response, served before any other check.
+#[derive(Debug, Clone)]
+struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+/// A local HTTP server mimicking the assets and api endpoints of
+/// everybody.codes.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+struct State {
+    session: String,
+    name: String,
+    seed: u32,
+    server_time: u64,
+    quests: Vec<Quest>,
+    canned: VecDeque<Response>,
+    requests: Vec<Request>,
+}
+
+impl Quest {
+    #[must_use]
+    pub fn event(year: u16, quest: u8) -> Self {
+        Self::new(
+            format!("{year}/{quest}"),
+            format!("event/{year}/quest/{quest}"),
+        )
+    }
+
+    /// A story quest, served under the URLs of the event with its number.
+    #[must_use]
+    pub fn story(number: u8, quest: u8) -> Self {
+        Self::event(number.into(), quest)
+    }
+
+    fn new(assets_path: String, api_path: String) -> Self {
+        Self {
+            assets_path,
+            api_path,
+            parts: vec![],
+            unlocked: 0,
+        }
+    }
+
+    /// Adds the next part, unlocked, with a key derived from the quest.
+    #[must_use]
+    pub fn part(mut self, data: &str, answer: &str) -> Self {
+        let key = format!("{}{}", self.api_path.replace('/', ""), self.parts.len() + 1)
+            .chars()
+            .cycle()
+            .take(KEY_LEN)
+            .collect();
+
+        self.parts.push(Part {
+            data: data.to_string(),
+            answer: answer.to_string(),
+            key,
+        });
+        self.unlocked = self.parts.len();
+        self
+    }
+
+    /// Keeps only the first `parts` parts unlocked: the others are served
+    /// encrypted, without their key and answer.
+    #[must_use]
+    pub fn unlocked(mut self, parts: usize) -> Self {
+        self.unlocked = parts.min(self.parts.len());
+        self
+    }
+
+    #[must_use]
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    fn input_notes(&self) -> serde_json::Value {
+        self.parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                (
+                    (index + 1).to_string(),
+                    encrypt(&part.data, &part.key)
+                        .expect("mock keys are KEY_LEN bytes")
+                        .into(),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+
+    fn keys(&self) -> serde_json::Value {
+        self.parts
+            .iter()
+            .take(self.unlocked)
+            .enumerate()
+            .flat_map(|(index, part)| {
+                [
+                    (format!("key{}", index + 1), part.key.clone().into()),
+                    (format!("answer{}", index + 1), part.answer.clone().into()),
+                ]
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+}
+
+impl MockServer {
+    /// Starts a server accepting only `session` and serving inputs for `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server cannot listen on a local port.
+    #[must_use]
+    pub fn start(session: &str, seed: u32) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("cannot bind mock server");
+        let addr = listener
+            .local_addr()
+            .expect("cannot get mock server address");
+
+        let state = Arc::new(Mutex::new(State {
+            session: session.to_string(),
+            name: "mock".to_string(),
+            seed,
+            server_time: 1_731_000_000_123,
+            quests: vec![],
+            canned: VecDeque::new(),
+            requests: vec![],
+        }));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handle = thread::spawn({
+            let state = Arc::clone(&state);
+            let shutdown = Arc::clone(&shutdown);
+            move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        let _ = serve(&state, stream);
+                    }
+                }
+            }
+        });
+
+        Self {
+            addr,
+            state,
+            shutdown,
+            handle: Some(handle),
+        }
+    }
+
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    #[must_use]
+    pub fn assets_url(&self) -> String {
+        format!("{}/assets", self.url())
+    }
+
+    #[must_use]
+    pub fn api_url(&self) -> String {
+        self.url()
+    }
+
+    #[must_use]
+    pub fn site_url(&self) -> String {
+        self.url()
+    }
+
+    /// Adds `quest`, replacing any previous version of it.
+    ///
+    /// # Panics
+    pub fn quest(&self, quest: Quest) {
+        let quests = &mut self.state.lock().unwrap().quests;
+        quests.retain(|other| other.api_path != quest.api_path);
+        quests.push(quest);
+    }
+
+    /// Answers the next request with `status`, before any other check.
+    ///
+    /// # Panics
+    pub fn fail(&self, status: u16) {
+        self.respond(status, &[], "");
+    }
+
+    /// Answers the next request with `status`, the extra `headers` and
+    /// `body`, before any other check. Canned responses are served in order.
+    ///
+    /// # Panics
+    pub fn respond(&self, status: u16, headers: &[(&str, &str)], body: &str) {
+        self.state.lock().unwrap().canned.push_back(Response {
+            status,
+            headers: headers
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.to_string(),
+        });
+    }
+
+    /// # Panics
+    #[must_use]
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl State {
+    fn quest(&self, path: &str) -> Option<&Quest> {
+        self.quests.iter().find(|quest| quest.api_path == path)
+    }
+
+    fn respond(&mut self, request: &Request) -> Response {
+        if let Some(response) = self.canned.pop_front() {
+            return response;
+        }
+
+        let cookie = format!("everybody-codes={}", self.session);
+        if !request
+            .headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("cookie"))
+            .flat_map(|(_, value)| value.split(';'))
+            .any(|value| value.trim() == cookie)
+        {
+            return Response::status(401);
+        }
+
+        let path = request.path.trim_start_matches('/');
+        let input = format!("/input/{}.json", self.seed);
+
+        let body = match request.method.as_str() {
+            "GET" if path == "api/user/me" => Some(serde_json::json!({
+                "name": self.name,
+                "seed": self.seed,
+                "serverTime": self.server_time,
+            })),
+            "GET" => match path
+                .strip_prefix("assets/")
+                .and_then(|path| path.strip_suffix(&input))
+            {
+                Some(assets_path) => self
+                    .quests
+                    .iter()
+                    .find(|quest| quest.assets_path == assets_path)
+                    .map(Quest::input_notes),
+                None => self.quest(path).map(Quest::keys),
+            },
+            "POST" => path.rsplit_once("/part/").and_then(|(api_path, part)| {
+                let part = part.strip_suffix("/answer")?.parse::<usize>().ok()?;
+                let expected = &self
+                    .quest(api_path)?
+                    .parts
+                    .get(part.checked_sub(1)?)?
+                    .answer;
+                let answer = serde_json::from_str::<serde_json::Value>(&request.body).ok()?;
+                let answer = answer.get("answer")?.as_str()?;
+                Some(serde_json::json!({
+                    "correct": answer == expected,
+                    "lengthCorrect": answer.len() == expected.len(),
+                    "firstCorrect": answer.chars().next() == expected.chars().next(),
+                }))
+            }),
+            _ => None,
+        };
+
+        match body {
+            Some(body) => Response {
+                status: 200,
+                headers: vec![],
+                body: body.to_string(),
+            },
+            None => Response::status(404),
+        }
+    }
+}
+
+impl Response {
+    fn status(status: u16) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: String::new(),
+        }
+    }
+}
+
+fn serve(state: &Mutex<State>, stream: TcpStream) -> io::Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut request_line = line.split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+
+    let mut headers = vec![];
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line == "\r\n" {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim().to_string();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().unwrap_or_default();
+            }
+            headers.push((name.to_string(), value));
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let request = Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        let response = state.respond(&request);
+        state.requests.push(request);
+        response
+    };
+
+    let stream = reader.get_mut();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\n",
+        response.status,
+        reason(response.status),
+    )?;
+    for (name, value) in &response.headers {
+        write!(stream, "{name}: {value}\r\n")?;
+    }
+    write!(
+        stream,
+        "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.body.len(),
+        response.body,
+    )
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
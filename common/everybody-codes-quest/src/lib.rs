@@ -0,0 +1,11 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod puzzle;
+pub mod quest;
+pub mod registry;
+pub mod samples;
+
+pub use puzzle::PuzzleId;
+pub use quest::Quest;
@@ -0,0 +1,74 @@
+use alloc::{format, string::String};
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PuzzleId {
+    Event { year: u16, quest: u8 },
+    Story { number: u8, quest: u8 },
+}
+
+impl PuzzleId {
+    #[must_use]
+    pub const fn quest(self) -> u8 {
+        match self {
+            Self::Event { quest, .. } | Self::Story { quest, .. } => quest,
+        }
+    }
+
+    #[must_use]
+    pub fn command_args(self) -> String {
+        match self {
+            Self::Event { year, quest } => format!("{year} {quest}"),
+            Self::Story { number, quest } => format!("story {number} {quest}"),
+        }
+    }
+
+    /// The package name of the quest crate, also used as benchmark group.
+    #[must_use]
+    pub fn slug(self) -> String {
+        match self {
+            Self::Event { year, quest } => format!("event-{year}-{quest:02}"),
+            Self::Story { number, quest } => format!("story-{number}-{quest:02}"),
+        }
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Event { year, quest } => write!(f, "event {year} quest {quest}"),
+            Self::Story { number, quest } => write!(f, "story {number} quest {quest}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_event() {
+        let id = PuzzleId::Event {
+            year: 2024,
+            quest: 7,
+        };
+
+        assert_eq!(id.command_args(), "2024 7");
+        assert_eq!(id.slug(), "event-2024-07");
+        assert_eq!(id.to_string(), "event 2024 quest 7");
+    }
+
+    #[test]
+    fn test_story() {
+        let id = PuzzleId::Story {
+            number: 3,
+            quest: 2,
+        };
+
+        assert_eq!(id.command_args(), "story 3 2");
+        assert_eq!(id.slug(), "story-3-02");
+        assert_eq!(id.to_string(), "story 3 quest 2");
+    }
+}
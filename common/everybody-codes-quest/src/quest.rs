@@ -0,0 +1,29 @@
+use core::fmt;
+
+use crate::puzzle::PuzzleId;
+
+pub struct Unavailable;
+
+impl fmt::Display for Unavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unavailable")
+    }
+}
+
+pub trait Quest {
+    const ID: PuzzleId;
+    const PARTS: u8 = 3;
+
+    #[must_use]
+    fn part_1(data: &str) -> impl fmt::Display;
+
+    #[must_use]
+    fn part_2(_data: &str) -> impl fmt::Display {
+        Unavailable
+    }
+
+    #[must_use]
+    fn part_3(_data: &str) -> impl fmt::Display {
+        Unavailable
+    }
+}
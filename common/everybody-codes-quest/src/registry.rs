@@ -0,0 +1,250 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::puzzle::PuzzleId;
+use crate::quest::Quest;
+
+pub type Solver = fn(&str) -> String;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    pub puzzle: PuzzleId,
+    pub data_dir: &'static str,
+    pub parts: &'static [Solver],
+}
+
+impl Entry {
+    #[must_use]
+    pub const fn new(puzzle: PuzzleId, data_dir: &'static str, parts: &'static [Solver]) -> Self {
+        Self {
+            puzzle,
+            data_dir,
+            parts,
+        }
+    }
+
+    #[must_use]
+    pub const fn of<Q: Quest>(data_dir: &'static str) -> Self {
+        let parts: &'static [Solver; 3] = &const {
+            [
+                |data: &str| answer(Q::part_1(data)),
+                |data: &str| answer(Q::part_2(data)),
+                |data: &str| answer(Q::part_3(data)),
+            ]
+        };
+
+        Self::new(Q::ID, data_dir, parts.split_at(Q::PARTS as usize).0)
+    }
+
+    /// Runs the solver of `part` on `data`, if the quest has that part.
+    #[must_use]
+    pub fn solve(&self, part: u8, data: &str) -> Option<String> {
+        let solver = self.parts.get(usize::from(part).checked_sub(1)?)?;
+
+        Some(solver(data))
+    }
+
+    /// Runs the solver of `part` on the real input `data` and compares the
+    /// result with the recorded `answer`. Returns `false` when there is
+    /// nothing to check.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result differs from the recorded answer.
+    #[must_use]
+    pub fn check_real_input(&self, part: u8, data: Option<&str>, answer: Option<&str>) -> bool {
+        let (Some(data), Some(expected)) = (data, answer.map(str::trim)) else {
+            return false;
+        };
+        if expected.is_empty() {
+            return false;
+        }
+        let Some(result) = self.solve(part, data) else {
+            return false;
+        };
+
+        assert_eq!(
+            result.trim(),
+            expected,
+            "{} part {part} on real input",
+            self.puzzle
+        );
+
+        true
+    }
+}
+
+#[must_use]
+pub fn answer(value: impl fmt::Display) -> String {
+    value.to_string()
+}
+
+#[macro_export]
+macro_rules! register {
+    ($quest:ty) => {
+        pub const ENTRY: $crate::registry::Entry =
+            $crate::registry::Entry::of::<$quest>(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+
+        $crate::real_input_tests!();
+    };
+}
+
+/// Adds a `real_input_part_N` test per part, checking `ENTRY` against the
+/// recorded answers. Parts without data or answer are skipped.
+#[macro_export]
+macro_rules! real_input_tests {
+    () => {
+        #[cfg(test)]
+        mod real_input {
+            extern crate std;
+
+            fn check(part: u8) {
+                let read = |name: &str| {
+                    std::fs::read_to_string(std::format!("{}/{name}_{part}", super::ENTRY.data_dir))
+                        .ok()
+                };
+
+                if !super::ENTRY.check_real_input(
+                    part,
+                    read("part").as_deref(),
+                    read("answer").as_deref(),
+                ) {
+                    std::println!("skipped: no real input or answer");
+                }
+            }
+
+            #[test]
+            fn real_input_part_1() {
+                check(1);
+            }
+
+            #[test]
+            fn real_input_part_2() {
+                check(2);
+            }
+
+            #[test]
+            fn real_input_part_3() {
+                check(3);
+            }
+        }
+    };
+}
+
+/// Defines the `main` of a quest binary, running the parts of `$entry` on
+/// their input data.
+///
+/// Without arguments every part is run on `data/part_N` and printed with its
+/// timing, `PART` runs a single part, and `PART INPUT` prints only the answer
+/// of `PART` on the file `INPUT`.
+#[macro_export]
+macro_rules! main {
+    ($entry:expr) => {
+        fn main() -> std::process::ExitCode {
+            let entry: $crate::registry::Entry = $entry;
+            let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+            let part = match args.first().map(|part| part.parse::<u8>()) {
+                None => None,
+                Some(Ok(part)) if (1..=entry.parts.len()).contains(&usize::from(part)) => {
+                    Some(part)
+                }
+                Some(_) => {
+                    eprintln!(
+                        "usage: [PART [INPUT]], with PART in 1..={}",
+                        entry.parts.len()
+                    );
+                    return std::process::ExitCode::FAILURE;
+                }
+            };
+
+            if let (Some(part), Some(input)) = (part, args.get(1)) {
+                return match std::fs::read_to_string(input) {
+                    Ok(data) => {
+                        println!("{}", entry.solve(part, &data).unwrap_or_default());
+                        std::process::ExitCode::SUCCESS
+                    }
+                    Err(err) => {
+                        eprintln!("{input}: {err}");
+                        std::process::ExitCode::FAILURE
+                    }
+                };
+            }
+
+            for current in (1..=entry.parts.len()).filter_map(|part| u8::try_from(part).ok()) {
+                if part.is_some_and(|part| part != current) {
+                    continue;
+                }
+
+                let path = format!("{}/part_{current}", entry.data_dir);
+                let data = match std::fs::read_to_string(&path) {
+                    Ok(data) if !data.trim().is_empty() => data,
+                    _ => {
+                        println!("part {current}: missing input {path}");
+                        continue;
+                    }
+                };
+
+                let start = std::time::Instant::now();
+                let result = std::panic::catch_unwind(|| entry.solve(current, &data));
+                let elapsed = start.elapsed();
+
+                match result {
+                    Ok(answer) => {
+                        println!(
+                            "part {current}: {} ({elapsed:?})",
+                            answer.unwrap_or_default()
+                        );
+                    }
+                    Err(_) => println!("part {current}: failed ({elapsed:?})"),
+                }
+            }
+
+            std::process::ExitCode::SUCCESS
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum(data: &str) -> String {
+        answer(
+            data.split(',')
+                .map(|n| n.parse::<u32>().unwrap())
+                .sum::<u32>(),
+        )
+    }
+
+    const ENTRY: Entry = Entry::new(
+        PuzzleId::Event {
+            year: 2024,
+            quest: 1,
+        },
+        "data",
+        &[sum as Solver; 2],
+    );
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(ENTRY.solve(2, "4,5,6").as_deref(), Some("15"));
+        assert_eq!(ENTRY.solve(3, "4,5,6"), None);
+        assert_eq!(ENTRY.solve(0, "4,5,6"), None);
+    }
+
+    #[test]
+    fn test_check_real_input() {
+        assert!(ENTRY.check_real_input(1, Some("1,2,3"), Some("6\n")));
+        assert!(!ENTRY.check_real_input(2, Some("4,5,6"), None));
+        assert!(!ENTRY.check_real_input(2, Some("4,5,6"), Some(" \n")));
+        assert!(!ENTRY.check_real_input(2, None, Some("15")));
+        assert!(!ENTRY.check_real_input(3, Some("1"), Some("1")));
+    }
+
+    #[test]
+    #[should_panic(expected = "event 2024 quest 1 part 1 on real input")]
+    fn test_check_real_input_wrong() {
+        let _ = ENTRY.check_real_input(1, Some("1,2,3"), Some("7"));
+    }
+}
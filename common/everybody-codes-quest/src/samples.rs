@@ -0,0 +1,70 @@
+use alloc::string::ToString;
+
+use crate::quest::Quest;
+
+/// Checks the answer of `Q` for `part` on `input`.
+///
+/// # Panics
+///
+/// Panics if the answer differs from `expected`.
+pub fn check<Q: Quest>(part: u8, input: &str, expected: &str) {
+    let answer = match part {
+        1 => Q::part_1(input).to_string(),
+        2 => Q::part_2(input).to_string(),
+        3 => Q::part_3(input).to_string(),
+        _ => panic!("invalid part {part}"),
+    };
+
+    assert_eq!(answer, expected.trim(), "{} part {part} on sample", Q::ID);
+}
+
+/// Adds a test per sample generated by `everybody_codes::load_samples` from
+/// the build script, checking them against `$quest`.
+#[macro_export]
+macro_rules! sample_tests {
+    ($quest:ident) => {
+        #[cfg(test)]
+        mod samples {
+            #[allow(dead_code)]
+            fn check(part: u8, input: &str, expected: &str) {
+                $crate::samples::check::<super::$quest>(part, input, expected);
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/samples.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt;
+
+    use super::*;
+    use crate::PuzzleId;
+
+    struct Sum;
+
+    impl Quest for Sum {
+        const ID: PuzzleId = PuzzleId::Event {
+            year: 2024,
+            quest: 1,
+        };
+
+        fn part_1(data: &str) -> impl fmt::Display {
+            data.split(',')
+                .map(|n| n.trim().parse::<u32>().unwrap())
+                .sum::<u32>()
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        check::<Sum>(1, "1,2,3", "6\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "event 2024 quest 1 part 1 on sample")]
+    fn test_check_wrong() {
+        check::<Sum>(1, "1,2", "4");
+    }
+}
@@ -0,0 +1,442 @@
+use std::{collections::BTreeMap, fs, io, path};
+
+use crate::PuzzleId;
+use crate::client::{Client, InputNotesError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("cache directory not found")]
+    MissingCacheDir,
+    #[error("cannot download input notes of {puzzle}")]
+    InputNotes {
+        puzzle: PuzzleId,
+        #[source]
+        source: InputNotesError,
+    },
+    #[error("cannot access cache")]
+    IO(#[from] io::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PartState {
+    /// The server has no key for the part yet.
+    Locked,
+    /// The previous part was solved since the part was found locked.
+    Unlocked,
+    Fetched,
+    Solved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartStatus {
+    pub part: u8,
+    pub state: Option<PartState>,
+}
+
+/// The state of each part of a quest, stored next to the cached parts.
+#[derive(Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    parts: BTreeMap<String, PartState>,
+}
+
+impl Manifest {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<PartState> {
+        self.parts.get(&part.to_string()).copied()
+    }
+
+    pub fn set(&mut self, part: u8, state: PartState) {
+        self.parts.insert(part.to_string(), state);
+    }
+
+    // Solving a part through `ec submit` unlocks the next one.
+    fn solve(&mut self, part: u8) {
+        self.set(part, PartState::Solved);
+        if self.get(part + 1) == Some(PartState::Locked) {
+            self.set(part + 1, PartState::Unlocked);
+        }
+    }
+}
+
+pub struct Cache {
+    root: path::PathBuf,
+}
+
+impl Cache {
+    #[must_use]
+    pub fn new(root: impl Into<path::PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// # Errors
+    pub fn new_default() -> Result<Self, Error> {
+        Ok(Self::new(
+            dirs::cache_dir()
+                .ok_or(Error::MissingCacheDir)?
+                .join("everybody-codes"),
+        ))
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &path::Path {
+        &self.root
+    }
+
+    #[must_use]
+    pub fn quest_dir(&self, puzzle: PuzzleId, seed: u32) -> path::PathBuf {
+        let dir = match puzzle {
+            PuzzleId::Event { year, .. } => self.root.join(year.to_string()),
+            PuzzleId::Story { number, .. } => self.root.join("story").join(number.to_string()),
+        };
+
+        dir.join(puzzle.quest().to_string()).join(seed.to_string())
+    }
+
+    #[must_use]
+    pub fn part_path(&self, puzzle: PuzzleId, seed: u32, part: u8) -> path::PathBuf {
+        self.quest_dir(puzzle, seed).join(format!("part_{part}"))
+    }
+
+    #[must_use]
+    pub fn answer_path(&self, puzzle: PuzzleId, seed: u32, part: u8) -> path::PathBuf {
+        self.quest_dir(puzzle, seed).join(format!("answer_{part}"))
+    }
+
+    #[must_use]
+    pub fn manifest_path(&self, puzzle: PuzzleId, seed: u32) -> path::PathBuf {
+        self.quest_dir(puzzle, seed).join("manifest.toml")
+    }
+
+    /// # Errors
+    pub fn manifest(&self, puzzle: PuzzleId, seed: u32) -> io::Result<Manifest> {
+        match read_non_empty(&self.manifest_path(puzzle, seed))? {
+            Some(manifest) => toml::from_str(&manifest)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            None => Ok(Manifest::default()),
+        }
+    }
+
+    fn write_manifest(&self, puzzle: PuzzleId, seed: u32, manifest: &Manifest) -> io::Result<bool> {
+        let manifest = toml::to_string(manifest)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        write_if_changed(&self.manifest_path(puzzle, seed), &manifest)
+    }
+
+    /// The state of `part`, reconciled with the cached files.
+    ///
+    /// # Errors
+    pub fn state(&self, puzzle: PuzzleId, seed: u32, part: u8) -> io::Result<Option<PartState>> {
+        Ok(if self.answer(puzzle, seed, part)?.is_some() {
+            Some(PartState::Solved)
+        } else if self.part(puzzle, seed, part)?.is_some() {
+            Some(PartState::Fetched)
+        } else {
+            self.manifest(puzzle, seed)?
+                .get(part)
+                .filter(|state| matches!(state, PartState::Locked | PartState::Unlocked))
+        })
+    }
+
+    /// # Errors
+    pub fn part(&self, puzzle: PuzzleId, seed: u32, part: u8) -> io::Result<Option<String>> {
+        read_non_empty(&self.part_path(puzzle, seed, part))
+    }
+
+    /// # Errors
+    pub fn answer(&self, puzzle: PuzzleId, seed: u32, part: u8) -> io::Result<Option<String>> {
+        read_non_empty(&self.answer_path(puzzle, seed, part))
+    }
+
+    /// Downloads the input notes of `puzzle` when one of `parts` is missing
+    /// or may have been unlocked since.
+    ///
+    /// # Errors
+    pub fn fetch(&self, client: &Client, puzzle: PuzzleId, parts: &[u8]) -> Result<bool, Error> {
+        let seed = client.seed();
+        let mut missing = false;
+        for &part in parts {
+            missing |= match self.state(puzzle, seed, part)? {
+                None | Some(PartState::Unlocked) => true,
+                // The previous part may have been solved on the website.
+                Some(PartState::Locked) => {
+                    part == 1 || self.state(puzzle, seed, part - 1)? != Some(PartState::Solved)
+                }
+                Some(PartState::Fetched | PartState::Solved) => false,
+            };
+        }
+
+        if missing {
+            self.refresh(client, puzzle)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// # Errors
+    pub fn refresh(&self, client: &Client, puzzle: PuzzleId) -> Result<bool, Error> {
+        let seed = client.seed();
+        let input_notes = client
+            .input_notes(puzzle)
+            .map_err(|source| Error::InputNotes { puzzle, source })?;
+
+        let mut manifest = self.manifest(puzzle, seed)?;
+        let mut modified = false;
+        for (part, data, answer) in [
+            (1, input_notes.part_1, input_notes.answer_1),
+            (2, input_notes.part_2, input_notes.answer_2),
+            (3, input_notes.part_3, input_notes.answer_3),
+        ] {
+            if let Some(data) = data {
+                modified |= write_if_changed(&self.part_path(puzzle, seed, part), &data)?;
+                manifest.set(part, PartState::Fetched);
+            } else {
+                manifest.set(part, PartState::Locked);
+            }
+            if let Some(answer) = answer {
+                modified |= write_if_changed(&self.answer_path(puzzle, seed, part), &answer)?;
+                manifest.set(part, PartState::Solved);
+            }
+        }
+        modified |= self.write_manifest(puzzle, seed, &manifest)?;
+
+        Ok(modified)
+    }
+
+    /// Records the accepted answer of `part`.
+    ///
+    /// # Errors
+    pub fn solve(&self, puzzle: PuzzleId, seed: u32, part: u8, answer: &str) -> io::Result<bool> {
+        let mut manifest = self.manifest(puzzle, seed)?;
+        manifest.solve(part);
+
+        Ok(
+            write_if_changed(&self.answer_path(puzzle, seed, part), answer.trim())?
+                | self.write_manifest(puzzle, seed, &manifest)?,
+        )
+    }
+
+    /// # Errors
+    pub fn status(&self, puzzle: PuzzleId, seed: u32, parts: &[u8]) -> io::Result<Vec<PartStatus>> {
+        parts
+            .iter()
+            .map(|&part| {
+                Ok(PartStatus {
+                    part,
+                    state: self.state(puzzle, seed, part)?,
+                })
+            })
+            .collect()
+    }
+
+    /// # Errors
+    pub fn clear(&self, puzzle: PuzzleId, seed: u32) -> io::Result<bool> {
+        match fs::remove_dir_all(self.quest_dir(puzzle, seed)) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// # Errors
+    pub fn clear_all(&self) -> io::Result<bool> {
+        match fs::remove_dir_all(&self.root) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+fn read_non_empty(path: &path::Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(data) if data.trim().is_empty() => Ok(None),
+        Ok(data) => Ok(Some(data)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub(crate) fn write_if_changed(path: &path::Path, data: &str) -> io::Result<bool> {
+    if fs::read_to_string(path).is_ok_and(|local| local == data) {
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, data)?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use everybody_codes_mock::{MockServer, Quest};
+
+    use super::*;
+
+    fn cache(name: &str) -> Cache {
+        let root = std::env::temp_dir().join(format!(
+            "everybody-codes-cache-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        Cache::new(root)
+    }
+
+    const PUZZLE: PuzzleId = PuzzleId::Event {
+        year: 2025,
+        quest: 12,
+    };
+
+    #[test]
+    fn test_story_is_not_event() {
+        let cache = cache("story");
+
+        assert_ne!(
+            cache.quest_dir(PuzzleId::Event { year: 3, quest: 2 }, 42),
+            cache.quest_dir(
+                PuzzleId::Story {
+                    number: 3,
+                    quest: 2
+                },
+                42
+            )
+        );
+    }
+
+    #[test]
+    fn test_fetch_and_refresh() {
+        let cache = cache("fetch");
+
+        let server = MockServer::start("secret", 42);
+        server.quest(
+            Quest::event(2025, 12)
+                .part("part 1", "1")
+                .part("part 2", "2")
+                .unlocked(1),
+        );
+        let client =
+            Client::new("secret".to_string(), 42).with_urls(server.assets_url(), server.api_url());
+
+        assert!(cache.fetch(&client, PUZZLE, &[1]).unwrap());
+        assert!(!cache.fetch(&client, PUZZLE, &[1]).unwrap());
+        assert_eq!(server.requests().len(), 2);
+
+        assert_eq!(
+            cache.part(PUZZLE, 42, 1).unwrap().as_deref(),
+            Some("part 1")
+        );
+        assert_eq!(cache.answer(PUZZLE, 42, 1).unwrap().as_deref(), Some("1"));
+        assert_eq!(cache.part(PUZZLE, 42, 2).unwrap(), None);
+
+        assert!(!cache.refresh(&client, PUZZLE).unwrap());
+        assert_eq!(server.requests().len(), 4);
+
+        assert_eq!(cache.state(PUZZLE, 42, 2).unwrap(), Some(PartState::Locked));
+        assert!(!cache.fetch(&client, PUZZLE, &[1, 2]).unwrap());
+        assert_eq!(server.requests().len(), 4);
+
+        cache.clear_all().unwrap();
+    }
+
+    #[test]
+    fn test_fetch_locked_part() {
+        let cache = cache("locked");
+
+        let server = MockServer::start("secret", 42);
+        server.quest(
+            Quest::event(2025, 12)
+                .part("part 1", "1")
+                .part("part 2", "2")
+                .unlocked(0),
+        );
+        let client =
+            Client::new("secret".to_string(), 42).with_urls(server.assets_url(), server.api_url());
+
+        assert!(cache.fetch(&client, PUZZLE, &[1, 2]).unwrap());
+        assert!(!cache.fetch(&client, PUZZLE, &[1, 2]).unwrap());
+        assert_eq!(server.requests().len(), 4);
+
+        // Part 1 is fetched but solved on the website, not through the cache.
+        fs::write(cache.part_path(PUZZLE, 42, 1), "part 1").unwrap();
+        server.quest(
+            Quest::event(2025, 12)
+                .part("part 1", "1")
+                .part("part 2", "2")
+                .unlocked(2),
+        );
+        assert!(cache.fetch(&client, PUZZLE, &[2]).unwrap());
+        assert_eq!(cache.state(PUZZLE, 42, 2).unwrap(), Some(PartState::Solved));
+
+        cache.clear_all().unwrap();
+    }
+
+    #[test]
+    fn test_solve_unlocks_next_part() {
+        let cache = cache("solve");
+
+        let server = MockServer::start("secret", 42);
+        server.quest(
+            Quest::event(2025, 12)
+                .part("part 1", "1")
+                .part("part 2", "2")
+                .unlocked(0),
+        );
+        let client =
+            Client::new("secret".to_string(), 42).with_urls(server.assets_url(), server.api_url());
+
+        assert!(cache.fetch(&client, PUZZLE, &[1, 2]).unwrap());
+        assert_eq!(cache.state(PUZZLE, 42, 1).unwrap(), Some(PartState::Locked));
+
+        cache.solve(PUZZLE, 42, 1, "1").unwrap();
+        assert_eq!(cache.state(PUZZLE, 42, 1).unwrap(), Some(PartState::Solved));
+        assert_eq!(
+            cache.state(PUZZLE, 42, 2).unwrap(),
+            Some(PartState::Unlocked)
+        );
+
+        server.quest(
+            Quest::event(2025, 12)
+                .part("part 1", "1")
+                .part("part 2", "2")
+                .unlocked(2),
+        );
+        assert!(cache.fetch(&client, PUZZLE, &[1, 2]).unwrap());
+        assert_eq!(cache.state(PUZZLE, 42, 2).unwrap(), Some(PartState::Solved));
+
+        cache.clear_all().unwrap();
+    }
+
+    #[test]
+    fn test_status_and_clear() {
+        let cache = cache("status");
+
+        fs::create_dir_all(cache.quest_dir(PUZZLE, 42)).unwrap();
+        fs::write(cache.part_path(PUZZLE, 42, 1), "data").unwrap();
+        fs::write(cache.answer_path(PUZZLE, 42, 1), "answer").unwrap();
+        fs::write(cache.part_path(PUZZLE, 42, 2), "  \n").unwrap();
+
+        assert_eq!(
+            cache.status(PUZZLE, 42, &[1, 2]).unwrap(),
+            vec![
+                PartStatus {
+                    part: 1,
+                    state: Some(PartState::Solved),
+                },
+                PartStatus {
+                    part: 2,
+                    state: None,
+                },
+            ]
+        );
+
+        assert!(cache.clear(PUZZLE, 42).unwrap());
+        assert!(!cache.clear(PUZZLE, 42).unwrap());
+        assert_eq!(cache.part(PUZZLE, 42, 1).unwrap(), None);
+
+        cache.clear_all().unwrap();
+    }
+}
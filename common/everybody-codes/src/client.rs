@@ -1,45 +1,162 @@
-use std::{fs, io};
-
-use aes::cipher::{BlockDecryptMut, KeyIvInit, block_padding};
+use std::{
+    io, path,
+    sync::OnceLock,
+    thread,
+    time::{Duration, SystemTime},
+};
 
 use reqwest::{
-    blocking::{Client as HttpClient, Response},
+    StatusCode,
+    blocking::{Client as HttpClient, RequestBuilder, Response},
     header::{self, HeaderMap, HeaderValue},
     redirect::Policy,
 };
 
-type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+const ASSETS_URL: &str = "https://everybody.codes/assets";
+const API_URL: &str = "https://api.everybody.codes";
+const SITE_URL: &str = "https://everybody.codes";
+const CONCURRENCY: usize = 4;
+
+use crate::PuzzleId;
+use crate::config::{self, SESSION_ENV};
+use crate::limiter::Limiter;
+use crate::notes;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("everybody-codes.cfg not found")]
-    MissingCfg,
-    #[error("everybody-codes.cfg invalid")]
+    #[error("config directory not found")]
+    MissingConfigDir,
+    #[error(
+        "no session configured: create {} with `session = \"<everybody-codes cookie>\"` or set ${SESSION_ENV}",
+        path.display()
+    )]
+    MissingCfg { path: path::PathBuf },
+    #[error("cannot read config file {}", path.display())]
+    ReadCfg {
+        path: path::PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("invalid config file {}", path.display())]
+    InvalidCfgFile {
+        path: path::PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    #[error("invalid config")]
     InvalidCfg(#[from] toml::de::Error),
-    #[error("IO Error")]
-    IO(#[from] io::Error),
+    #[error("invalid profile `{0}`: expected a string")]
+    InvalidProfile(String),
+    #[error("unknown profile `{0}`: add a [profiles.{0}] table")]
+    UnknownProfile(String),
+    #[error("invalid ${name}: `{value}`")]
+    InvalidEnv { name: &'static str, value: String },
+    #[error("`seed` missing in {}: set it, or run `ec me` to look it up", path.display())]
+    MissingSeed { path: path::PathBuf },
+    #[error("`seed` missing in {}: cannot read it from the user profile", path.display())]
+    Profile {
+        path: path::PathBuf,
+        #[source]
+        source: HttpError,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HttpError {
+    #[error("invalid session: it must be a valid header value")]
+    InvalidSession(#[from] header::InvalidHeaderValue),
+    #[error("cannot build HTTP client")]
+    Builder(#[source] reqwest::Error),
+    #[error("cannot acquire a request slot")]
+    Limiter(#[from] io::Error),
+    #[error("request to {url} failed")]
+    Request {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error(
+        "{url} rejected the session (HTTP {status}): the cookie has probably expired, update `session` in everybody-codes.cfg"
+    )]
+    Unauthorized { url: String, status: StatusCode },
+    #[error(
+        "{url} not found (HTTP {status}): check that the quest is unlocked and that `seed` in everybody-codes.cfg is correct"
+    )]
+    NotFound { url: String, status: StatusCode },
+    #[error("{url} failed with HTTP {status}")]
+    Status { url: String, status: StatusCode },
+    #[error("invalid response from {url}")]
+    Body {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum InputNotesError {
-    #[error("Invalid header")]
-    InvalidHeader(#[from] header::InvalidHeaderValue),
-    #[error("Internal Error")]
-    Internal(#[from] reqwest::Error),
-    #[error("Invalid data")]
-    InvalidData(#[from] serde_json::Error),
-    #[error("Decode error")]
-    FromHex(#[from] hex::FromHexError),
-    #[error("UTF8 error")]
-    Utf8(#[from] std::str::Utf8Error),
-    #[error("Unpad error")]
-    Unpad(#[from] block_padding::UnpadError),
+    #[error(transparent)]
+    Http(#[from] HttpError),
+    #[error("cannot decrypt part {part}")]
+    Decrypt {
+        part: u8,
+        #[source]
+        source: notes::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubmitAnswerError {
+    #[error(transparent)]
+    Http(#[from] HttpError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+pub struct Retry {
+    pub max_retries: u32,
+    #[serde(rename = "base_delay_ms", deserialize_with = "millis")]
+    pub base_delay: Duration,
+    #[serde(rename = "max_delay_ms", deserialize_with = "millis")]
+    pub max_delay: Duration,
 }
 
 #[derive(Debug, serde::Deserialize)]
 pub struct Client {
     session: String,
     seed: u32,
+    #[serde(skip, default = "default_assets_url")]
+    assets_url: String,
+    #[serde(skip, default = "default_api_url")]
+    api_url: String,
+    #[serde(skip, default = "default_site_url")]
+    site_url: String,
+    #[serde(default)]
+    retry: Retry,
+    #[serde(default = "default_concurrency")]
+    concurrency: usize,
+    #[serde(skip)]
+    limiter: Option<Limiter>,
+    #[serde(skip)]
+    http: OnceLock<HttpClient>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerOutcome {
+    Correct,
+    Wrong,
+    WrongLength,
+    WrongFirstChar,
+    RateLimited { retry_after: Option<Duration> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub name: String,
+    pub seed: u32,
+    #[serde(deserialize_with = "epoch_millis")]
+    pub server_time: SystemTime,
 }
 
 #[derive(Debug)]
@@ -73,6 +190,60 @@ pub struct KeysResponse {
     answer3: Option<String>,
 }
 
+#[derive(Debug, serde::Serialize)]
+struct AnswerRequest<'a> {
+    answer: &'a str,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AnswerResponse {
+    correct: bool,
+    length_correct: bool,
+    first_correct: bool,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl Retry {
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(1 << attempt.min(16))
+            .min(self.max_delay)
+    }
+}
+
+impl From<AnswerResponse> for AnswerOutcome {
+    fn from(response: AnswerResponse) -> Self {
+        if response.correct {
+            Self::Correct
+        } else if !response.length_correct {
+            Self::WrongLength
+        } else if !response.first_correct {
+            Self::WrongFirstChar
+        } else {
+            Self::Wrong
+        }
+    }
+}
+
 impl TryFrom<&str> for Client {
     type Error = Error;
 
@@ -92,21 +263,116 @@ impl TryFrom<String> for Client {
 impl Client {
     /// # Errors
     pub fn new_from_config() -> Result<Self, Error> {
-        fs::read_to_string(
-            dirs::config_dir()
-                .ok_or(Error::MissingCfg)?
-                .join("everybody-codes.cfg"),
-        )?
-        .try_into()
+        let (path, config) = config::load()?;
+
+        Ok(Self::from_config(&path, config)?.with_default_limiter())
+    }
+
+    /// Like [`Client::new_from_config`], but reads a missing `seed` from the
+    /// user profile. This sends a request, so build scripts use
+    /// [`Client::new_from_config`] instead.
+    ///
+    /// # Errors
+    pub fn new_from_config_or_profile() -> Result<Self, Error> {
+        let (path, mut config) = config::load()?;
+        if config.contains_key("seed") {
+            return Ok(Self::from_config(&path, config)?.with_default_limiter());
+        }
+
+        config.insert("seed".to_string(), 0.into());
+        Self::from_config(&path, config)?
+            .with_default_limiter()
+            .with_profile_seed(&path)
+    }
+
+    fn from_config(path: &path::Path, config: toml::Table) -> Result<Self, Error> {
+        if !config.contains_key("seed") {
+            return Err(Error::MissingSeed {
+                path: path.to_path_buf(),
+            });
+        }
+
+        toml::Value::Table(config)
+            .try_into::<Self>()
+            .map_err(|source| Error::InvalidCfgFile {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+
+    fn with_default_limiter(self) -> Self {
+        let limiter = dirs::cache_dir().map(|dir| {
+            Limiter::new(
+                dir.join("everybody-codes").join("locks"),
+                self.concurrency.max(1),
+            )
+        });
+
+        self.with_limiter(limiter)
+    }
+
+    fn with_profile_seed(mut self, path: &path::Path) -> Result<Self, Error> {
+        self.seed = self
+            .me()
+            .map_err(|source| Error::Profile {
+                path: path.to_path_buf(),
+                source,
+            })?
+            .seed;
+
+        Ok(self)
     }
 
     #[must_use]
     pub fn new(session: String, seed: u32) -> Self {
-        Self { session, seed }
+        Self {
+            session,
+            seed,
+            assets_url: default_assets_url(),
+            api_url: default_api_url(),
+            site_url: default_site_url(),
+            retry: Retry::default(),
+            concurrency: default_concurrency(),
+            limiter: None,
+            http: OnceLock::new(),
+        }
     }
 
-    /// # Errors
-    pub fn input_notes(&self, event: u16, quest: u8) -> Result<InputNotes, InputNotesError> {
+    #[must_use]
+    pub fn with_urls(mut self, assets_url: impl Into<String>, api_url: impl Into<String>) -> Self {
+        self.assets_url = assets_url.into();
+        self.api_url = api_url.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_site_url(mut self, site_url: impl Into<String>) -> Self {
+        self.site_url = site_url.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_retry(mut self, retry: Retry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    #[must_use]
+    pub fn with_limiter(mut self, limiter: Option<Limiter>) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    #[must_use]
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    fn http_client(&self) -> Result<&HttpClient, HttpError> {
+        if let Some(client) = self.http.get() {
+            return Ok(client);
+        }
+
         let cookie_header = HeaderValue::from_str(&format!("everybody-codes={}", self.session))?;
         let accept_header = HeaderValue::from_str("application/json")?;
         let user_agent_header = HeaderValue::from_str(&format!(
@@ -123,52 +389,490 @@ impl Client {
         let client = HttpClient::builder()
             .default_headers(headers)
             .redirect(Policy::none())
-            .build()?;
+            .build()
+            .map_err(HttpError::Builder)?;
 
-        let url = format!(
-            "https://everybody.codes/assets/{event}/{quest}/input/{}.json",
-            self.seed
-        );
+        Ok(self.http.get_or_init(|| client))
+    }
+
+    fn send(&self, url: &str, request: RequestBuilder) -> Result<Response, HttpError> {
+        let _permit = self.limiter.as_ref().map(Limiter::acquire).transpose()?;
+        request.send().map_err(|source| HttpError::Request {
+            url: url.to_string(),
+            source,
+        })
+    }
+
+    // Only for idempotent requests: retries on connection errors, 429 and 5xx.
+    fn get<T>(&self, url: &str) -> Result<T, HttpError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let client = self.http_client()?;
 
-        let input_notes: InputNotesResponse = client
-            .get(url)
-            .send()
-            .and_then(Response::error_for_status)
-            .and_then(Response::json)?;
+        let mut attempt = 0;
+        let response = loop {
+            let delay = match self.send(url, client.get(url)) {
+                Ok(response)
+                    if response.status() == StatusCode::TOO_MANY_REQUESTS
+                        || response.status().is_server_error() =>
+                {
+                    if attempt == self.retry.max_retries {
+                        break response;
+                    }
+                    retry_after(&response).map_or_else(
+                        || self.retry.delay(attempt),
+                        |delay| delay.min(self.retry.max_delay),
+                    )
+                }
+                Ok(response) => break response,
+                Err(err) => {
+                    if attempt == self.retry.max_retries {
+                        return Err(err);
+                    }
+                    self.retry.delay(attempt)
+                }
+            };
 
-        let url = format!("https://api.everybody.codes/event/{event}/quest/{quest}");
+            thread::sleep(delay);
+            attempt += 1;
+        };
 
-        let keys: KeysResponse = client
-            .get(url)
-            .send()
-            .and_then(Response::error_for_status)
-            .and_then(Response::json)?;
+        json(url, response)
+    }
+
+    /// # Errors
+    pub fn me(&self) -> Result<Profile, HttpError> {
+        self.get(&format!("{}/api/user/me", self.site_url))
+    }
+
+    /// # Errors
+    pub fn input_notes(&self, puzzle: PuzzleId) -> Result<InputNotes, InputNotesError> {
+        let input_notes: InputNotesResponse = self.get(&format!(
+            "{}/{}/input/{}.json",
+            self.assets_url,
+            assets_path(puzzle),
+            self.seed
+        ))?;
+
+        let keys: KeysResponse = self.get(&format!("{}/{}", self.api_url, api_path(puzzle)))?;
+
+        let decrypt = |part, encrypted_text: Option<String>, key: Option<String>| {
+            encrypted_text
+                .zip(key)
+                .map(|(encrypted_text, key)| notes::decrypt(&encrypted_text, &key))
+                .transpose()
+                .map_err(|source| InputNotesError::Decrypt { part, source })
+        };
 
         Ok(InputNotes {
-            part_1: input_notes
-                .part_1
-                .and_then(|encrypted_text| keys.key1.map(|key| decrypt(&encrypted_text, &key)))
-                .transpose()?,
+            part_1: decrypt(1, input_notes.part_1, keys.key1)?,
             answer_1: keys.answer1,
-            part_2: input_notes
-                .part_2
-                .and_then(|encrypted_text| keys.key2.map(|key| decrypt(&encrypted_text, &key)))
-                .transpose()?,
+            part_2: decrypt(2, input_notes.part_2, keys.key2)?,
             answer_2: keys.answer2,
-            part_3: input_notes
-                .part_3
-                .and_then(|encrypted_text| keys.key3.map(|key| decrypt(&encrypted_text, &key)))
-                .transpose()?,
+            part_3: decrypt(3, input_notes.part_3, keys.key3)?,
             answer_3: keys.answer3,
         })
     }
+
+    /// # Errors
+    pub fn submit_answer(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<AnswerOutcome, SubmitAnswerError> {
+        let client = self.http_client()?;
+
+        let url = format!("{}/{}/part/{part}/answer", self.api_url, api_path(puzzle));
+
+        let response = self.send(&url, client.post(&url).json(&AnswerRequest { answer }))?;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            return Ok(AnswerOutcome::RateLimited {
+                retry_after: retry_after(&response),
+            });
+        }
+
+        let response: AnswerResponse = json(&url, response)?;
+
+        Ok(response.into())
+    }
+}
+
+/// The server numbers stories like events, so both share the same URLs.
+fn event_quest(puzzle: PuzzleId) -> (u16, u8) {
+    match puzzle {
+        PuzzleId::Event { year, quest } => (year, quest),
+        PuzzleId::Story { number, quest } => (number.into(), quest),
+    }
+}
+
+fn assets_path(puzzle: PuzzleId) -> String {
+    let (event, quest) = event_quest(puzzle);
+    format!("{event}/{quest}")
+}
+
+fn api_path(puzzle: PuzzleId) -> String {
+    let (event, quest) = event_quest(puzzle);
+    format!("event/{event}/quest/{quest}")
+}
+
+fn default_assets_url() -> String {
+    ASSETS_URL.to_string()
+}
+
+fn default_api_url() -> String {
+    API_URL.to_string()
+}
+
+fn default_site_url() -> String {
+    SITE_URL.to_string()
+}
+
+fn default_concurrency() -> usize {
+    CONCURRENCY
+}
+
+fn millis<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    <u64 as serde::Deserialize>::deserialize(deserializer).map(Duration::from_millis)
+}
+
+fn epoch_millis<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    millis(deserializer).map(|duration| SystemTime::UNIX_EPOCH + duration)
+}
+
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+fn json<T>(url: &str, response: Response) -> Result<T, HttpError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let url = url.to_string();
+    let status = response.status();
+
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            Err(HttpError::Unauthorized { url, status })
+        }
+        StatusCode::NOT_FOUND => Err(HttpError::NotFound { url, status }),
+        status if !status.is_success() => Err(HttpError::Status { url, status }),
+        _ => response
+            .json()
+            .map_err(|source| HttpError::Body { url, source }),
+    }
 }
 
-fn decrypt(encrypted_text: &str, key: &str) -> Result<String, InputNotesError> {
-    let encrypted_bytes = hex::decode(encrypted_text)?;
+#[cfg(test)]
+mod tests {
+    use everybody_codes_mock::{MockServer, Quest};
+
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(assets_path(PUZZLE), "2024/7");
+        assert_eq!(api_path(PUZZLE), "event/2024/quest/7");
+
+        let story = PuzzleId::Story {
+            number: 3,
+            quest: 2,
+        };
+        assert_eq!(assets_path(story), "3/2");
+        assert_eq!(api_path(story), "event/3/quest/2");
+    }
+
+    #[test]
+    fn test_submit_answer_request() {
+        let server = mock();
+
+        let _ = client(&server, "secret")
+            .submit_answer(PUZZLE, 2, "ABCDEFGHIJK")
+            .unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/event/2024/quest/7/part/2/answer");
+        assert!(
+            request
+                .headers
+                .iter()
+                .any(|(name, value)| name.eq_ignore_ascii_case("cookie")
+                    && value.contains("everybody-codes=secret"))
+        );
+        assert_eq!(request.body, r#"{"answer":"ABCDEFGHIJK"}"#);
+    }
+
+    #[test]
+    fn test_submit_answer_correct() {
+        assert_eq!(submit("ABCDEFGHIJK"), AnswerOutcome::Correct);
+    }
+
+    #[test]
+    fn test_submit_answer_wrong() {
+        assert_eq!(submit("ABCDEFGHIJX"), AnswerOutcome::Wrong);
+    }
+
+    #[test]
+    fn test_submit_answer_wrong_length() {
+        assert_eq!(submit("ABCDEFGHIJ"), AnswerOutcome::WrongLength);
+    }
+
+    #[test]
+    fn test_submit_answer_wrong_first_char() {
+        assert_eq!(submit("XBCDEFGHIJK"), AnswerOutcome::WrongFirstChar);
+    }
+
+    #[test]
+    fn test_submit_answer_rate_limited() {
+        let server = mock();
+        server.respond(429, &[("Retry-After", "30")], "");
+
+        let outcome = client(&server, "secret")
+            .submit_answer(PUZZLE, 3, "ABCDEFGHIJK")
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            AnswerOutcome::RateLimited {
+                retry_after: Some(Duration::from_secs(30))
+            }
+        );
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let retry = Retry {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+
+        assert_eq!(retry.delay(0), Duration::from_millis(100));
+        assert_eq!(retry.delay(2), Duration::from_millis(400));
+        assert_eq!(retry.delay(3), Duration::from_millis(500));
+        assert_eq!(retry.delay(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_retry_config() {
+        let client = Client::try_from(
+            "session = \"secret\"\nseed = 42\n\n[retry]\nmax_retries = 5\nbase_delay_ms = 10\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            client.retry,
+            Retry {
+                max_retries: 5,
+                base_delay: Duration::from_millis(10),
+                ..Retry::default()
+            }
+        );
+    }
+
+    const PUZZLE: PuzzleId = PuzzleId::Event {
+        year: 2024,
+        quest: 7,
+    };
+
+    fn mock() -> MockServer {
+        let server = MockServer::start("secret", 42);
+        server.quest(
+            Quest::event(2024, 7)
+                .part("+,-,=,=", "BDCA")
+                .part("A:+,-,=,=\nB:+,=,-,+", "DCBA")
+                .part("S+===\n-   +\n=+=-+", "ABCDEFGHIJK")
+                .unlocked(2),
+        );
+        server
+    }
+
+    fn client(server: &MockServer, session: &str) -> Client {
+        Client::new(session.to_string(), 42)
+            .with_urls(server.assets_url(), server.api_url())
+            .with_site_url(server.site_url())
+            .with_retry(Retry {
+                max_retries: 1,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(1),
+            })
+    }
+
+    fn submit(answer: &str) -> AnswerOutcome {
+        let server = mock();
+
+        client(&server, "secret")
+            .submit_answer(PUZZLE, 3, answer)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_input_notes() {
+        let server = mock();
+
+        let input_notes = client(&server, "secret").input_notes(PUZZLE).unwrap();
 
-    let data = Aes256CbcDec::new(key.as_bytes().into(), key.as_bytes()[..16].into())
-        .decrypt_padded_vec_mut::<block_padding::Pkcs7>(&encrypted_bytes)?;
+        assert_eq!(input_notes.part_1.as_deref(), Some("+,-,=,="));
+        assert_eq!(input_notes.answer_1.as_deref(), Some("BDCA"));
+        assert_eq!(input_notes.part_2.as_deref(), Some("A:+,-,=,=\nB:+,=,-,+"));
+        assert_eq!(input_notes.answer_2.as_deref(), Some("DCBA"));
+        assert_eq!(input_notes.part_3, None);
+        assert_eq!(input_notes.answer_3, None);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/assets/2024/7/input/42.json");
+        assert_eq!(requests[1].path, "/event/2024/quest/7");
+    }
+
+    #[test]
+    fn test_input_notes_retry() {
+        let server = mock();
+        server.fail(503);
+
+        let input_notes = client(&server, "secret").input_notes(PUZZLE).unwrap();
+
+        assert_eq!(input_notes.answer_1.as_deref(), Some("BDCA"));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn test_input_notes_gives_up() {
+        let server = mock();
+        server.fail(503);
+        server.fail(503);
+
+        let err = client(&server, "secret").input_notes(PUZZLE).unwrap_err();
+
+        assert!(matches!(
+            err,
+            InputNotesError::Http(HttpError::Status {
+                status: StatusCode::SERVICE_UNAVAILABLE,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_input_notes_unauthorized() {
+        let server = mock();
+
+        let err = client(&server, "expired").input_notes(PUZZLE).unwrap_err();
+
+        assert!(matches!(
+            &err,
+            InputNotesError::Http(HttpError::Unauthorized { url, .. }) if url.ends_with("/2024/7/input/42.json")
+        ));
+        assert!(err.to_string().contains("the cookie has probably expired"));
+    }
+
+    #[test]
+    fn test_input_notes_not_found() {
+        let server = mock();
+
+        let err = client(&server, "secret")
+            .input_notes(PuzzleId::Story {
+                number: 24,
+                quest: 7,
+            })
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            InputNotesError::Http(HttpError::NotFound { .. })
+        ));
+    }
 
-    Ok(std::str::from_utf8(&data)?.to_string())
+    #[test]
+    fn test_input_notes_decrypt_error() {
+        let server = mock();
+        server.respond(200, &[], r#"{"1":"00"}"#);
+        server.respond(200, &[], r#"{"key1":"0123456789abcdef"}"#);
+
+        let err = client(&server, "secret").input_notes(PUZZLE).unwrap_err();
+
+        assert_eq!(
+            crate::Report(err).to_string(),
+            "cannot decrypt part 1: invalid key length 16: expected 32 bytes"
+        );
+    }
+
+    #[test]
+    fn test_input_notes_caps_retry_after() {
+        let server = mock();
+        server.respond(429, &[("Retry-After", "3600")], "");
+        let start = std::time::Instant::now();
+
+        let input_notes = client(&server, "secret").input_notes(PUZZLE).unwrap();
+
+        assert_eq!(input_notes.answer_1.as_deref(), Some("BDCA"));
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_me() {
+        let server = mock();
+
+        let profile = client(&server, "secret").me().unwrap();
+
+        assert_eq!(server.requests()[0].path, "/api/user/me");
+        assert_eq!(
+            profile,
+            Profile {
+                name: "mock".to_string(),
+                seed: 42,
+                server_time: SystemTime::UNIX_EPOCH + Duration::from_millis(1_731_000_000_123),
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_requires_seed() {
+        let err = Client::from_config(
+            path::Path::new("everybody-codes.cfg"),
+            toml::from_str("session = \"secret\"").unwrap(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`seed` missing in everybody-codes.cfg: set it, or run `ec me` to look it up"
+        );
+    }
+
+    #[test]
+    fn test_profile_seed() {
+        let server = mock();
+
+        let client = client(&server, "secret")
+            .with_profile_seed(path::Path::new("everybody-codes.cfg"))
+            .unwrap();
+
+        assert_eq!(client.seed(), 42);
+        assert_eq!(server.requests()[0].path, "/api/user/me");
+    }
+
+    #[test]
+    fn test_config_keeps_seed() {
+        let client = Client::from_config(
+            path::Path::new("everybody-codes.cfg"),
+            toml::from_str("session = \"secret\"\nseed = 7").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(client.seed(), 7);
+    }
 }
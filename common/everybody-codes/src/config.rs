@@ -0,0 +1,217 @@
+use std::{env, fs, io, path};
+
+use crate::client::Error;
+
+pub const CONFIG_ENV: &str = "EVERYBODY_CODES_CONFIG";
+pub const PROFILE_ENV: &str = "EVERYBODY_CODES_PROFILE";
+pub const SESSION_ENV: &str = "EVERYBODY_CODES_SESSION";
+pub const SEED_ENV: &str = "EVERYBODY_CODES_SEED";
+
+pub const ENV_VARS: [&str; 4] = [CONFIG_ENV, PROFILE_ENV, SESSION_ENV, SEED_ENV];
+
+pub const CONFIG_FILE: &str = "everybody-codes.cfg";
+pub const WORKSPACE_FILE: &str = ".everybody-codes.toml";
+
+/// Loads the configuration, from lowest to highest priority:
+/// - the user config, `$EVERYBODY_CODES_CONFIG` or `everybody-codes.cfg` in the
+///   config directory
+/// - the nearest `.everybody-codes.toml` in the current directory or its ancestors
+/// - the profile selected by `$EVERYBODY_CODES_PROFILE` or the `profile` key,
+///   replacing the top-level `session` and `seed`
+/// - `$EVERYBODY_CODES_SESSION` and `$EVERYBODY_CODES_SEED`
+///
+/// Returns the user config path along with the resolved table.
+///
+/// # Errors
+pub fn load() -> Result<(path::PathBuf, toml::Table), Error> {
+    let (path, required) = match env::var_os(CONFIG_ENV) {
+        Some(path) => (path::PathBuf::from(path), true),
+        None => (
+            dirs::config_dir()
+                .ok_or(Error::MissingConfigDir)?
+                .join(CONFIG_FILE),
+            false,
+        ),
+    };
+
+    let mut config = read(&path, required)?.unwrap_or_default();
+
+    if let Some(workspace) = env::current_dir().ok().and_then(|dir| {
+        dir.ancestors()
+            .map(|dir| dir.join(WORKSPACE_FILE))
+            .find(|path| path.is_file())
+    }) {
+        merge(&mut config, read(&workspace, true)?.unwrap_or_default());
+    }
+
+    let config = resolve(config, |name| env::var(name).ok())?;
+    if !config.contains_key("session") {
+        return Err(Error::MissingCfg { path });
+    }
+
+    Ok((path, config))
+}
+
+fn read(path: &path::Path, required: bool) -> Result<Option<toml::Table>, Error> {
+    let config = match fs::read_to_string(path) {
+        Ok(config) => config,
+        Err(err) if err.kind() == io::ErrorKind::NotFound && !required => return Ok(None),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::MissingCfg {
+                path: path.to_path_buf(),
+            });
+        }
+        Err(source) => {
+            return Err(Error::ReadCfg {
+                path: path.to_path_buf(),
+                source,
+            });
+        }
+    };
+
+    toml::from_str(&config)
+        .map(Some)
+        .map_err(|source| Error::InvalidCfgFile {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+fn merge(config: &mut toml::Table, other: toml::Table) {
+    for (key, value) in other {
+        match (config.get_mut(&key), value) {
+            (Some(toml::Value::Table(table)), toml::Value::Table(other)) => merge(table, other),
+            (_, value) => {
+                config.insert(key, value);
+            }
+        }
+    }
+}
+
+fn resolve(
+    mut config: toml::Table,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<toml::Table, Error> {
+    let profiles = config.remove("profiles");
+    let profile = config.remove("profile");
+
+    let profile = match env(PROFILE_ENV) {
+        Some(profile) => Some(profile),
+        None => profile
+            .map(|profile| {
+                profile
+                    .as_str()
+                    .map(str::to_string)
+                    .ok_or(Error::InvalidProfile(profile.to_string()))
+            })
+            .transpose()?,
+    };
+
+    if let Some(profile) = profile {
+        let Some(toml::Value::Table(table)) = profiles
+            .as_ref()
+            .and_then(|profiles| profiles.get(&profile))
+        else {
+            return Err(Error::UnknownProfile(profile));
+        };
+        // The session and seed belong together, so a profile never mixes its
+        // own with the ones of the base table.
+        config.remove("session");
+        config.remove("seed");
+        merge(&mut config, table.clone());
+    }
+
+    if let Some(session) = env(SESSION_ENV) {
+        config.insert("session".to_string(), session.into());
+    }
+
+    if let Some(seed) = env(SEED_ENV) {
+        let seed = seed.trim().parse::<u32>().map_err(|_| Error::InvalidEnv {
+            name: SEED_ENV,
+            value: seed,
+        })?;
+        config.insert("seed".to_string(), i64::from(seed).into());
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+session = "user"
+seed = 1
+
+[profiles.alice]
+session = "alice"
+seed = 2
+
+[profiles.bob]
+session = "bob"
+"#;
+
+    fn table(config: &str) -> toml::Table {
+        toml::from_str(config).unwrap()
+    }
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn test_default() {
+        let config = resolve(table(CONFIG), no_env).unwrap();
+
+        assert_eq!(config, table("session = \"user\"\nseed = 1"));
+    }
+
+    #[test]
+    fn test_workspace_profile() {
+        let mut config = table(CONFIG);
+        merge(
+            &mut config,
+            table("profile = \"bob\"\n[profiles.bob]\nseed = 3"),
+        );
+
+        let config = resolve(config, no_env).unwrap();
+
+        assert_eq!(config, table("session = \"bob\"\nseed = 3"));
+    }
+
+    #[test]
+    fn test_profile_without_seed() {
+        let config = resolve(table(CONFIG), |name| {
+            (name == PROFILE_ENV).then(|| "bob".to_string())
+        })
+        .unwrap();
+
+        assert_eq!(config, table("session = \"bob\""));
+    }
+
+    #[test]
+    fn test_env() {
+        let config = resolve(table(CONFIG), |name| match name {
+            PROFILE_ENV => Some("alice".to_string()),
+            SEED_ENV => Some("4".to_string()),
+            _ => None,
+        })
+        .unwrap();
+
+        assert_eq!(config, table("session = \"alice\"\nseed = 4"));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            resolve(table(CONFIG), |name| (name == PROFILE_ENV).then(|| "carol".to_string())),
+            Err(Error::UnknownProfile(profile)) if profile == "carol"
+        ));
+        assert!(matches!(
+            resolve(table(CONFIG), |name| (name == SEED_ENV)
+                .then(|| "seed".to_string())),
+            Err(Error::InvalidEnv { name: SEED_ENV, .. })
+        ));
+    }
+}
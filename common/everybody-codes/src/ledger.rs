@@ -0,0 +1,100 @@
+use std::{fmt, fs, io, path};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+pub struct Ledger<'a> {
+    data_dir: &'a path::Path,
+}
+
+impl<'a> Ledger<'a> {
+    #[must_use]
+    pub fn new(data_dir: &'a str) -> Self {
+        Self {
+            data_dir: path::Path::new(data_dir),
+        }
+    }
+
+    #[must_use]
+    pub fn answer_path(&self, part: u8) -> path::PathBuf {
+        self.data_dir.join(format!("answer_{part}"))
+    }
+
+    /// # Errors
+    pub fn answer(&self, part: u8) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.answer_path(part)) {
+            Ok(answer) if answer.trim().is_empty() => Ok(None),
+            Ok(answer) => Ok(Some(answer.trim().to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// # Errors
+    pub fn record(&self, part: u8, answer: &str) -> io::Result<bool> {
+        if self.answer(part)?.as_deref() == Some(answer.trim()) {
+            return Ok(false);
+        }
+
+        fs::create_dir_all(self.data_dir)?;
+        fs::write(self.answer_path(part), answer.trim())?;
+
+        Ok(true)
+    }
+
+    /// # Errors
+    pub fn check(&self, part: u8, result: impl fmt::Display) -> io::Result<Verdict> {
+        Ok(match self.answer(part)? {
+            Some(expected) if expected == result.to_string().trim() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong { expected },
+            None => Verdict::Unknown,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_dir(name: &str) -> String {
+        let data_dir = std::env::temp_dir().join(format!(
+            "everybody-codes-ledger-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&data_dir);
+        data_dir.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_unknown() {
+        let data_dir = data_dir("unknown");
+        let ledger = Ledger::new(&data_dir);
+
+        assert_eq!(ledger.answer(1).unwrap(), None);
+        assert_eq!(ledger.check(1, 42).unwrap(), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_record_and_check() {
+        let data_dir = data_dir("record");
+        let ledger = Ledger::new(&data_dir);
+
+        assert!(ledger.record(2, "1234\n").unwrap());
+        assert!(!ledger.record(2, "1234").unwrap());
+
+        assert_eq!(ledger.answer(2).unwrap().as_deref(), Some("1234"));
+        assert_eq!(ledger.check(2, 1234).unwrap(), Verdict::Correct);
+        assert_eq!(
+            ledger.check(2, "4321").unwrap(),
+            Verdict::Wrong {
+                expected: "1234".to_string()
+            }
+        );
+
+        fs::remove_dir_all(&data_dir).unwrap();
+    }
+}
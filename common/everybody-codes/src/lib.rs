@@ -1,8 +1,31 @@
+pub mod cache;
 pub mod client;
+pub mod config;
+pub mod ledger;
+pub mod limiter;
+pub mod notes;
 pub mod parts_data;
+pub mod report;
+pub mod samples;
 
+pub use everybody_codes_quest::PuzzleId;
+pub use report::Report;
+
+/// # Errors
+pub fn load_parts(data_dir: &str) -> Result<bool, Report<parts_data::Error>> {
+    Ok(parts_data::PartsData::new_from_cargo(data_dir)?.load_if_necessary()?)
+}
+
+/// Generates the tests of the samples in `samples_dir` for
+/// `everybody_codes_quest::sample_tests!`, from a build script.
+///
 /// # Errors
-pub fn fetch_parts(data_dir: &str) -> Result<bool, parts_data::Error> {
-    parts_data::PartsData::new_from_cargo(data_dir)?
-        .store_if_necessary()
+pub fn load_samples(samples_dir: &str) -> Result<usize, Report<samples::Error>> {
+    let var = |name| std::env::var_os(name).ok_or(samples::Error::MissingEnv(name));
+    let dir = std::path::Path::new(&var("CARGO_MANIFEST_DIR")?).join(samples_dir);
+    let out = std::path::Path::new(&var("OUT_DIR")?).join("samples.rs");
+
+    println!("cargo::rerun-if-changed={samples_dir}");
+
+    Ok(samples::generate(&dir, &out)?)
 }
@@ -0,0 +1,98 @@
+use std::{fs, io, path, thread, time::Duration};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Limits the number of concurrent requests across processes, using one lock
+/// file per slot.
+#[derive(Debug, Clone)]
+pub struct Limiter {
+    dir: path::PathBuf,
+    slots: usize,
+}
+
+/// A held slot, released on drop.
+#[derive(Debug)]
+pub struct Permit {
+    _file: fs::File,
+}
+
+impl Limiter {
+    /// # Panics
+    ///
+    /// Panics if `slots` is zero.
+    #[must_use]
+    pub fn new(dir: impl Into<path::PathBuf>, slots: usize) -> Self {
+        assert!(slots > 0, "at least one slot is required");
+
+        Self {
+            dir: dir.into(),
+            slots,
+        }
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &path::Path {
+        &self.dir
+    }
+
+    #[must_use]
+    pub fn slots(&self) -> usize {
+        self.slots
+    }
+
+    /// # Errors
+    pub fn try_acquire(&self) -> io::Result<Option<Permit>> {
+        fs::create_dir_all(&self.dir)?;
+
+        for slot in 0..self.slots {
+            let file = fs::OpenOptions::new()
+                .create(true)
+                .truncate(false)
+                .write(true)
+                .open(self.dir.join(format!("slot_{slot}.lock")))?;
+
+            match file.try_lock() {
+                Ok(()) => return Ok(Some(Permit { _file: file })),
+                Err(fs::TryLockError::WouldBlock) => {}
+                Err(fs::TryLockError::Error(err)) => return Err(err),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// # Errors
+    pub fn acquire(&self) -> io::Result<Permit> {
+        loop {
+            if let Some(permit) = self.try_acquire()? {
+                return Ok(permit);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slots() {
+        let dir =
+            std::env::temp_dir().join(format!("everybody-codes-limiter-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let limiter = Limiter::new(&dir, 2);
+
+        let first = limiter.try_acquire().unwrap();
+        let second = limiter.try_acquire().unwrap();
+        assert!(first.is_some());
+        assert!(second.is_some());
+        assert!(limiter.try_acquire().unwrap().is_none());
+
+        drop(first);
+        assert!(limiter.try_acquire().unwrap().is_some());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
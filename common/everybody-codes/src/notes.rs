@@ -0,0 +1,171 @@
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit, block_padding::Pkcs7};
+
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+pub const KEY_LEN: usize = 32;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("invalid hex data")]
+    FromHex(#[from] hex::FromHexError),
+    #[error("invalid key length {0}: expected {KEY_LEN} bytes")]
+    KeyLength(usize),
+    #[error("invalid padding: the key does not match the data")]
+    Unpad(#[from] aes::cipher::block_padding::UnpadError),
+    #[error("decrypted data is not UTF-8")]
+    Utf8(#[from] std::str::Utf8Error),
+}
+
+/// A part of the input notes, in clear.
+#[derive(Debug, Clone, Copy)]
+pub struct Part<'a> {
+    pub data: &'a str,
+    pub answer: Option<&'a str>,
+}
+
+/// The input notes as served by everybody.codes: the encrypted parts, and
+/// the keys with the answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sealed {
+    pub input: serde_json::Value,
+    pub keys: serde_json::Value,
+}
+
+/// Encrypts `data` with AES-256-CBC, using the first 16 bytes of `key` as IV,
+/// into a hex string.
+///
+/// # Errors
+pub fn encrypt(data: &str, key: &str) -> Result<String, Error> {
+    let key = check_key(key)?;
+
+    Ok(hex::encode(
+        Aes256CbcEnc::new(key.into(), key[..16].into())
+            .encrypt_padded_vec_mut::<Pkcs7>(data.as_bytes()),
+    ))
+}
+
+/// Decrypts a hex string produced by [`encrypt`].
+///
+/// # Errors
+pub fn decrypt(encrypted_text: &str, key: &str) -> Result<String, Error> {
+    let encrypted_bytes = hex::decode(encrypted_text)?;
+    let key = check_key(key)?;
+
+    let data = Aes256CbcDec::new(key.into(), key[..16].into())
+        .decrypt_padded_vec_mut::<Pkcs7>(&encrypted_bytes)?;
+
+    Ok(std::str::from_utf8(&data)?.to_string())
+}
+
+/// A key derived from `data` with FNV-1a, stable across runs and Rust
+/// releases.
+#[must_use]
+pub fn key(data: &str) -> String {
+    let hash = |salt: u8| {
+        [salt]
+            .iter()
+            .chain(data.as_bytes())
+            .fold(FNV_OFFSET, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    };
+
+    format!("{:016x}{:016x}", hash(0), hash(1))
+}
+
+/// Encrypts `parts` with keys derived from their data.
+///
+/// # Errors
+pub fn seal(parts: &[Part<'_>]) -> Result<Sealed, Error> {
+    let mut input = serde_json::Map::new();
+    let mut keys = serde_json::Map::new();
+
+    for (part, Part { data, answer }) in (1..).zip(parts) {
+        let key = key(data);
+
+        input.insert(part.to_string(), encrypt(data, &key)?.into());
+        keys.insert(format!("key{part}"), key.into());
+        if let Some(answer) = answer {
+            keys.insert(format!("answer{part}"), (*answer).into());
+        }
+    }
+
+    Ok(Sealed {
+        input: input.into(),
+        keys: keys.into(),
+    })
+}
+
+fn check_key(key: &str) -> Result<&[u8], Error> {
+    let key = key.as_bytes();
+    if key.len() == KEY_LEN {
+        Ok(key)
+    } else {
+        Err(Error::KeyLength(key.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn test_round_trip() {
+        let encrypted_text = encrypt("A:+,-,=,=\nB:+,=,-,+", KEY).unwrap();
+
+        assert_eq!(encrypted_text.len(), 64);
+        assert_eq!(
+            decrypt(&encrypted_text, KEY).unwrap(),
+            "A:+,-,=,=\nB:+,=,-,+"
+        );
+    }
+
+    #[test]
+    fn test_wrong_key() {
+        let encrypted_text = encrypt("+,-,=,=", KEY).unwrap();
+
+        assert!(matches!(
+            decrypt(&encrypted_text, &KEY[..16]),
+            Err(Error::KeyLength(16))
+        ));
+        assert!(decrypt(&encrypted_text, &key("other")).is_err());
+    }
+
+    #[test]
+    fn test_key() {
+        assert_eq!(key("1,2,3"), "665fd04a6ce5ccf557bfb5765b7ac994");
+        assert_eq!(key("1,2,3").len(), KEY_LEN);
+    }
+
+    #[test]
+    fn test_seal() {
+        let sealed = seal(&[
+            Part {
+                data: "1,2,3",
+                answer: Some("6"),
+            },
+            Part {
+                data: "4,5,6",
+                answer: None,
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(sealed.keys["answer1"], "6");
+        assert!(sealed.keys.get("answer2").is_none());
+        assert_eq!(
+            decrypt(
+                sealed.input["2"].as_str().unwrap(),
+                sealed.keys["key2"].as_str().unwrap()
+            )
+            .unwrap(),
+            "4,5,6"
+        );
+    }
+}
@@ -1,6 +1,11 @@
-use std::{fs, io, num, path, collections::HashSet};
+use std::{collections::HashSet, fs, io, path};
 
-use crate::client::{Client, Error as ClientError, InputNotesError};
+use crate::PuzzleId;
+use crate::cache::{self, Cache, PartState, write_if_changed};
+use crate::client::{Client, Error as ClientError};
+use crate::config;
+use crate::ledger::Ledger;
+use crate::report::Report;
 
 macro_rules! set {
     () => { std::collections::HashSet::new() };
@@ -17,139 +22,341 @@ macro_rules! set {
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("Client error")]
+    #[error("cannot load client config")]
     Client(#[from] ClientError),
-    #[error("Input notes error")]
-    InputNotes(#[from] InputNotesError),
-    #[error("Package metadata everybody_codes missing")]
-    PackageMetadataMissing,
-    #[error("IO error")]
+    #[error(transparent)]
+    Cache(#[from] cache::Error),
+    #[error("[package.metadata.everybody_codes] missing in {}", path.display())]
+    PackageMetadataMissing { path: path::PathBuf },
+    #[error("cannot read {}", path.display())]
+    ReadManifest {
+        path: path::PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("invalid manifest {}", path.display())]
+    Toml {
+        path: path::PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    #[error("cannot update data directory")]
     IO(#[from] io::Error),
-    #[error("Invalid toml")]
-    Toml(#[from] toml::de::Error),
-    #[error("Missing event")]
-    MissingEvent,
-    #[error("Missing quest")]
-    MissingQuest,
-    #[error("Invalid number conversion")]
-    Number(#[from] num::TryFromIntError),
-    #[error("Invalid number")]
-    InvalidNumber,
+    #[error("missing key `{0}` in [package.metadata.everybody_codes]")]
+    MissingKey(&'static str),
+    #[error(
+        "invalid kind `{0}` in [package.metadata.everybody_codes]: expected \"event\" or \"story\""
+    )]
+    InvalidKind(String),
+    #[error("invalid value `{value}` for key `{key}` in [package.metadata.everybody_codes]")]
+    InvalidNumber { key: &'static str, value: String },
+    #[error(
+        "part {part} of {puzzle} is not cached: run `ec fetch {}`",
+        puzzle.command_args()
+    )]
+    NotCached { puzzle: PuzzleId, part: u8 },
 }
 
 pub struct PartsData<'a> {
-    event: u16,
-    quest: u8,
+    puzzle: PuzzleId,
     parts: HashSet<u8>,
     data_dir: &'a str,
 }
 
 impl<'a> PartsData<'a> {
     #[must_use]
-    pub fn new(event: u16, quest: u8, parts: HashSet<u8>, data_dir: &'a str) -> Self {
-        Self { event, quest, parts, data_dir }            
+    pub fn new(puzzle: PuzzleId, parts: HashSet<u8>, data_dir: &'a str) -> Self {
+        Self {
+            puzzle,
+            parts,
+            data_dir,
+        }
     }
 
     /// # Errors
     pub fn new_from_cargo(data_dir: &'a str) -> Result<Self, Error> {
-        let config = fs::read_to_string("Cargo.toml")?
-            .parse::<toml::Table>()?;
+        Self::new_from_manifest(path::Path::new("Cargo.toml"), data_dir)
+    }
+
+    /// Reads the quest of the crate with manifest `path`.
+    ///
+    /// # Errors
+    pub fn new_from_manifest(path: &path::Path, data_dir: &'a str) -> Result<Self, Error> {
+        let path = path.to_path_buf();
+        let config = match fs::read_to_string(&path) {
+            Ok(config) => config,
+            Err(source) => return Err(Error::ReadManifest { path, source }),
+        };
+        let config = match config.parse::<toml::Table>() {
+            Ok(config) => config,
+            Err(source) => return Err(Error::Toml { path, source }),
+        };
 
-        let data = config
+        let Some(data) = config
             .get("package")
             .and_then(|value| value.get("metadata"))
             .and_then(|value| value.get("everybody_codes"))
-            .ok_or(Error::PackageMetadataMissing)?;
+        else {
+            return Err(Error::PackageMetadataMissing { path });
+        };
+
+        Self::new_from_metadata(data, data_dir)
+    }
+
+    fn new_from_metadata(data: &toml::Value, data_dir: &'a str) -> Result<Self, Error> {
+        let get = |key| data.get(key).ok_or(Error::MissingKey(key));
+
+        let event = get("event")?;
+        let quest = get_int("quest", get("quest")?)?;
+        let kind = data.get("kind").map_or(Ok("event"), |kind| {
+            kind.as_str()
+                .ok_or_else(|| Error::InvalidKind(kind.to_string()))
+        })?;
+        let puzzle = match kind {
+            "event" => PuzzleId::Event {
+                year: get_int("event", event)?,
+                quest,
+            },
+            "story" => PuzzleId::Story {
+                number: get_int("event", event)?,
+                quest,
+            },
+            kind => return Err(Error::InvalidKind(kind.to_string())),
+        };
+        let parts = data
+            .get("parts")
+            .map(|value| get_ints("parts", value))
+            .transpose()?
+            .unwrap_or_else(|| set![1, 2, 3]);
+
+        Ok(Self {
+            puzzle,
+            parts,
+            data_dir,
+        })
+    }
+
+    #[must_use]
+    pub fn puzzle(&self) -> PuzzleId {
+        self.puzzle
+    }
 
-        let event = get_int(data.get("event").ok_or(Error::MissingEvent)?)?;
-        let quest = get_int(data.get("quest").ok_or(Error::MissingQuest)?)?;
-        let parts = data.get("parts").map(get_ints).transpose()?.unwrap_or_else(|| set![1, 2, 3]);
+    /// The parts of the quest, in order.
+    #[must_use]
+    pub fn parts(&self) -> Vec<u8> {
+        let mut parts = self.parts.iter().copied().collect::<Vec<_>>();
+        parts.sort_unstable();
 
-        Ok(Self { event, quest, parts, data_dir })
+        parts
     }
 
+    /// Copies the cached input of every part into the data directory. A part
+    /// with neither cached nor existing input gets an empty placeholder and a
+    /// build warning.
+    ///
     /// # Errors
-    pub fn store_if_necessary(&mut self) -> Result<bool, Error> {
-        let data_dir = path::Path::new(self.data_dir);
+    pub fn load_if_necessary(&self) -> Result<bool, Error> {
+        for name in config::ENV_VARS {
+            println!("cargo::rerun-if-env-changed={name}");
+        }
 
-        let (part_1_path, need_part_1, part_1_data) = self.need_part(data_dir, 1);
-        let (part_2_path, need_part_2, part_2_data) = self.need_part(data_dir, 2);
-        let (part_3_path, need_part_3, part_3_data) = self.need_part(data_dir, 3);
+        let source = Client::new_from_config()
+            .map_err(Error::from)
+            .and_then(|client| Ok((Cache::new_default()?, client.seed())));
 
-        if !need_part_1 && !need_part_2 && !need_part_3 {
-            return Ok(false);
+        if let Ok((cache, seed)) = &source {
+            println!(
+                "cargo::rerun-if-changed={}",
+                cache.quest_dir(self.puzzle, *seed).display()
+            );
         }
-        
-        let input_notes = Client::new_from_config()?.input_notes(self.event, self.quest)?;
-
-	let mut modified = false;
-	{
-	    let modified = &mut modified;
-	    let mut write_if_needed = move |need_part, remote_data, local_data, part_path| {
-		if need_part {
-		    if let Some(remote_data) = remote_data {
-			if let Some(local_data) = local_data {
-			    if remote_data != local_data {
-				fs::create_dir_all(data_dir)?;
-				fs::write(part_path, remote_data)?;
-				*modified = true;
-			    }
-			} else {
-			    fs::create_dir_all(data_dir)?;
-			    fs::write(part_path, remote_data)?;
-			    *modified = true;
-			}
-		    } else if local_data.is_none() {
-			fs::create_dir_all(data_dir)?;
-			fs::write(part_path, "")?;
-			*modified = true;			    
-		    }
-		}
-
-		Ok::<(), Error>(())
-	    };
-
-	    write_if_needed(need_part_1, input_notes.part_1, part_1_data, part_1_path)?;
-	    write_if_needed(need_part_2, input_notes.part_2, part_2_data, part_2_path)?;
-	    write_if_needed(need_part_3, input_notes.part_3, part_3_data, part_3_path)?;
-	}
 
-        Ok(modified)
+        self.load(&source)
     }
 
-    fn need_part(&self, data_dir: &path::Path, part: u8) -> (path::PathBuf, bool, Option<String>) {
-        let part_path = data_dir.join(format!("part_{part}"));
-        if self.parts.contains(&part) {
-            let (need_part, part_data) = match fs::read_to_string(&part_path) {
-                Ok(data) => (data.trim().is_empty(), Some(data)),
-                Err(_) => (true, None),
-            };
-            (part_path, need_part, part_data)
-        } else {
-            (part_path, false, None)
+    fn load(&self, source: &Result<(Cache, u32), Error>) -> Result<bool, Error> {
+        let data_dir = path::Path::new(self.data_dir);
+        let ledger = Ledger::new(self.data_dir);
+
+        let mut modified = false;
+        for part in self.parts() {
+            let part_path = data_dir.join(format!("part_{part}"));
+            let exists = fs::read_to_string(&part_path).is_ok_and(|data| !data.trim().is_empty());
+
+            if let Ok((cache, seed)) = source
+                && let Some(data) = cache.part(self.puzzle, *seed, part)?
+            {
+                modified |= write_if_changed(&part_path, &data)?;
+                if let Some(answer) = cache.answer(self.puzzle, *seed, part)? {
+                    modified |= ledger.record(part, &answer)?;
+                }
+            } else if let Ok((cache, seed)) = source
+                && let Some(PartState::Locked | PartState::Unlocked) =
+                    cache.state(self.puzzle, *seed, part)?
+            {
+                if !exists {
+                    println!(
+                        "cargo::warning=part {part} of {} is locked: run `ec fetch {}` once it is unlocked",
+                        self.puzzle,
+                        self.puzzle.command_args()
+                    );
+                    modified |= write_if_changed(&part_path, "")?;
+                }
+            } else if !exists {
+                // Keep the workspace building without input: the part then
+                // reports its missing input when it runs.
+                let reason = match source {
+                    Ok(_) => Error::NotCached {
+                        puzzle: self.puzzle,
+                        part,
+                    }
+                    .to_string(),
+                    Err(err) => format!("part {part} of {}: {}", self.puzzle, Report(err)),
+                };
+                println!("cargo::warning={reason}");
+                modified |= write_if_changed(&part_path, "")?;
+            }
         }
+
+        Ok(modified)
     }
 }
 
-fn get_int<T>(value: &toml::Value) -> Result<T, Error>
+fn get_int<T>(key: &'static str, value: &toml::Value) -> Result<T, Error>
 where
     T: TryFrom<i64>,
-    Error: From<<T as TryFrom<i64>>::Error>,
 {
-    match value {
-        toml::Value::Integer(v) => Ok(T::try_from(*v)?),
-        _ => Err(Error::InvalidNumber),
-    }
+    value
+        .as_integer()
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or_else(|| Error::InvalidNumber {
+            key,
+            value: value.to_string(),
+        })
 }
 
-fn get_ints<T>(value: &toml::Value) -> Result<HashSet<T>, Error>
+fn get_ints<T>(key: &'static str, value: &toml::Value) -> Result<HashSet<T>, Error>
 where
-    T: TryFrom<i64>,
-    Error: From<<T as TryFrom<i64>>::Error>,
-    T: Eq + std::hash::Hash,
+    T: TryFrom<i64> + Eq + std::hash::Hash,
 {
     match value {
-        toml::Value::Array(array) => array.iter().map(get_int).collect::<Result<HashSet<_>, _>>(),
-        _ => Err(Error::InvalidNumber),
+        toml::Value::Array(array) => array.iter().map(|value| get_int(key, value)).collect(),
+        _ => Err(Error::InvalidNumber {
+            key,
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use everybody_codes_mock::{MockServer, Quest};
+
+    use super::*;
+
+    fn metadata(metadata: &str) -> Result<PartsData<'static>, Error> {
+        PartsData::new_from_metadata(&metadata.parse::<toml::Table>().unwrap().into(), "data")
+    }
+
+    #[test]
+    fn test_story() {
+        let parts = metadata("kind = \"story\"\nevent = 2\nquest = 3\nparts = [1]").unwrap();
+
+        assert_eq!(
+            parts.puzzle,
+            PuzzleId::Story {
+                number: 2,
+                quest: 3
+            }
+        );
+        assert_eq!(parts.parts, set![1]);
     }
-}    
+
+    #[test]
+    fn test_load() {
+        let root =
+            std::env::temp_dir().join(format!("everybody-codes-parts-data-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let data_dir = root.join("data");
+        let data_dir = data_dir.to_str().unwrap();
+
+        let server = MockServer::start("secret", 42);
+        server.quest(
+            Quest::story(2, 3)
+                .part("1,2,3", "6")
+                .part("4,5,6", "15")
+                .unlocked(1),
+        );
+
+        let puzzle = PuzzleId::Story {
+            number: 2,
+            quest: 3,
+        };
+        let client =
+            Client::new("secret".to_string(), 42).with_urls(server.assets_url(), server.api_url());
+        let cache = Cache::new(root.join("cache"));
+        assert!(cache.fetch(&client, puzzle, &[1]).unwrap());
+
+        let parts = PartsData::new(puzzle, set![1], data_dir);
+        assert!(
+            parts
+                .load(&Ok((Cache::new(root.join("cache")), 42)))
+                .unwrap()
+        );
+        assert!(
+            !parts
+                .load(&Ok((Cache::new(root.join("cache")), 42)))
+                .unwrap()
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("data/part_1")).unwrap(),
+            "1,2,3"
+        );
+        assert_eq!(
+            Ledger::new(data_dir).answer(1).unwrap().as_deref(),
+            Some("6")
+        );
+
+        let parts = PartsData::new(puzzle, set![1, 2], data_dir);
+        assert!(
+            parts
+                .load(&Ok((Cache::new(root.join("cache")), 42)))
+                .unwrap()
+        );
+        assert_eq!(fs::read_to_string(root.join("data/part_2")).unwrap(), "");
+
+        let other = PuzzleId::Story {
+            number: 2,
+            quest: 4,
+        };
+        let other_dir = root.join("other");
+        let parts = PartsData::new(other, set![1], other_dir.to_str().unwrap());
+        assert!(parts.load(&Ok((cache, 42))).unwrap());
+        assert_eq!(fs::read_to_string(other_dir.join("part_1")).unwrap(), "");
+        assert!(!parts.load(&Err(Error::MissingKey("quest"))).unwrap());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            metadata("event = 2024").err().unwrap().to_string(),
+            "missing key `quest` in [package.metadata.everybody_codes]"
+        );
+        assert_eq!(
+            metadata("event = 2024\nquest = 300")
+                .err()
+                .unwrap()
+                .to_string(),
+            "invalid value `300` for key `quest` in [package.metadata.everybody_codes]"
+        );
+        assert_eq!(
+            metadata("kind = \"quest\"\nevent = 2024\nquest = 3")
+                .err()
+                .unwrap()
+                .to_string(),
+            "invalid kind `quest` in [package.metadata.everybody_codes]: expected \"event\" or \"story\""
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use std::{error, fmt};
+
+/// Displays an error followed by the chain of its sources.
+pub struct Report<E>(pub E);
+
+impl<E: error::Error> fmt::Display for Report<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+
+        let mut source = self.0.source();
+        while let Some(err) = source {
+            write!(f, ": {err}")?;
+            source = err.source();
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: error::Error> fmt::Debug for Report<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<E: error::Error> From<E> for Report<E> {
+    fn from(err: E) -> Self {
+        Self(err)
+    }
+}
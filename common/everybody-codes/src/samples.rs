@@ -0,0 +1,196 @@
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("missing ${0}: not in a build script")]
+    MissingEnv(&'static str),
+    #[error("cannot read samples in {path}")]
+    ReadDir { path: PathBuf, source: io::Error },
+    #[error("missing expected answer {path}")]
+    MissingAnswer { path: PathBuf },
+    #[error("cannot read {path}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("cannot write {path}")]
+    Write { path: PathBuf, source: io::Error },
+}
+
+/// A sample input, `part_N_M.txt`, with its expected answer in
+/// `part_N_M.answer`. A `part_N_M.ignore` file, holding the reason, marks the
+/// sample as ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub part: u8,
+    pub index: u8,
+    pub input: PathBuf,
+    pub answer: PathBuf,
+    pub ignore: Option<String>,
+}
+
+impl Sample {
+    #[must_use]
+    pub fn name(&self) -> String {
+        format!("part_{}_{}", self.part, self.index)
+    }
+
+    /// # Errors
+    pub fn read_input(&self) -> Result<String, Error> {
+        read(&self.input)
+    }
+
+    /// # Errors
+    pub fn read_answer(&self) -> Result<String, Error> {
+        Ok(read(&self.answer)?.trim().to_string())
+    }
+}
+
+/// Finds the samples in `dir`, sorted by part and index. A missing directory
+/// has no samples.
+///
+/// # Errors
+pub fn find(dir: &Path) -> Result<Vec<Sample>, Error> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(source) => {
+            return Err(Error::ReadDir {
+                path: dir.to_path_buf(),
+                source,
+            });
+        }
+    };
+
+    let mut samples = vec![];
+    for entry in entries {
+        let input = entry
+            .map_err(|source| Error::ReadDir {
+                path: dir.to_path_buf(),
+                source,
+            })?
+            .path();
+
+        let Some((part, index)) = input
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("part_")?.strip_suffix(".txt"))
+            .and_then(|name| name.split_once('_'))
+            .and_then(|(part, index)| Some((part.parse::<u8>().ok()?, index.parse::<u8>().ok()?)))
+            .filter(|(part, _)| (1..=3).contains(part))
+        else {
+            continue;
+        };
+
+        let answer = input.with_extension("answer");
+        if !answer.is_file() {
+            return Err(Error::MissingAnswer { path: answer });
+        }
+
+        let ignore = input.with_extension("ignore");
+        let ignore = if ignore.is_file() {
+            Some(read(&ignore)?.trim().to_string())
+        } else {
+            None
+        };
+
+        samples.push(Sample {
+            part,
+            index,
+            input,
+            answer,
+            ignore,
+        });
+    }
+    samples.sort_by_key(|sample| (sample.part, sample.index));
+
+    Ok(samples)
+}
+
+/// Writes a test per sample in `dir` into `out`, to be included by
+/// `everybody_codes_quest::sample_tests!`. Returns the number of samples.
+///
+/// # Errors
+pub fn generate(dir: &Path, out: &Path) -> Result<usize, Error> {
+    let samples = find(dir)?;
+
+    let mut code = String::new();
+    for sample in &samples {
+        code.push_str("#[test]\n");
+        if let Some(reason) = &sample.ignore {
+            writeln!(code, "#[ignore = {reason:?}]").unwrap();
+        }
+        writeln!(
+            code,
+            "fn {}() {{\n    check({}, include_str!({:?}), include_str!({:?}));\n}}\n",
+            sample.name(),
+            sample.part,
+            sample.input.display().to_string(),
+            sample.answer.display().to_string(),
+        )
+        .unwrap();
+    }
+
+    fs::write(out, code).map_err(|source| Error::Write {
+        path: out.to_path_buf(),
+        source,
+    })?;
+
+    Ok(samples.len())
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_and_generate() {
+        let dir =
+            std::env::temp_dir().join(format!("everybody-codes-samples-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        for (name, contents) in [
+            ("part_1_2.txt", "1,2,3"),
+            ("part_1_2.answer", "6\n"),
+            ("part_1_10.txt", "4"),
+            ("part_1_10.answer", "4"),
+            ("part_1_10.ignore", "too slow\n"),
+            ("part_2_1.txt", "1"),
+            ("part_2_1.answer", "1"),
+            ("notes.txt", "not a sample"),
+        ] {
+            fs::write(dir.join(name), contents).unwrap();
+        }
+
+        let samples = find(&dir).unwrap();
+        assert_eq!(
+            samples.iter().map(Sample::name).collect::<Vec<_>>(),
+            ["part_1_2", "part_1_10", "part_2_1"]
+        );
+        assert_eq!(samples[0].read_answer().unwrap(), "6");
+        assert_eq!(samples[1].ignore.as_deref(), Some("too slow"));
+
+        assert_eq!(samples[0].read_input().unwrap(), "1,2,3");
+
+        let out = dir.join("samples.rs");
+        assert_eq!(generate(&dir, &out).unwrap(), 3);
+        let code = fs::read_to_string(&out).unwrap();
+        assert!(code.contains("#[ignore = \"too slow\"]\nfn part_1_10() {"));
+        assert!(code.contains("fn part_2_1() {\n    check(2, include_str!("));
+
+        fs::remove_file(dir.join("part_2_1.answer")).unwrap();
+        assert!(matches!(find(&dir), Err(Error::MissingAnswer { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(find(&dir).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,435 @@
+use core::fmt;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::{Dir, Pos};
+
+type Word = u128;
+
+const BITS: usize = Word::BITS as usize;
+
+/// What happens to the cells shifted past a border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// They are dropped.
+    Clip,
+    /// They come back from the opposite border.
+    Wrap,
+}
+
+/// A `ROWS` by `COLS` set of cells, packed in `WORDS` 128-bit words per row,
+/// with word-parallel set operations and shifts. `COLS` must fit in the
+/// words: `BitGrid<100, 200, 2>` needs two words per row.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitGrid<const ROWS: usize, const COLS: usize, const WORDS: usize = 1> {
+    rows: [[Word; WORDS]; ROWS],
+}
+
+/// The number of neighbours of each cell, as bit planes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Counts<const ROWS: usize, const COLS: usize, const WORDS: usize = 1> {
+    planes: [BitGrid<ROWS, COLS, WORDS>; 4],
+}
+
+impl<const ROWS: usize, const COLS: usize, const WORDS: usize> BitGrid<ROWS, COLS, WORDS> {
+    const FITS: () = assert!(COLS <= WORDS * BITS, "COLS does not fit in WORDS words");
+
+    #[must_use]
+    pub const fn new() -> Self {
+        let () = Self::FITS;
+
+        Self {
+            rows: [[0; WORDS]; ROWS],
+        }
+    }
+
+    /// The cell at `(row, col)` wrapped around the borders.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub const fn wrap(row: isize, col: isize) -> Pos {
+        Pos::new(
+            row.rem_euclid(ROWS as isize) as usize,
+            col.rem_euclid(COLS as isize) as usize,
+        )
+    }
+
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        ROWS
+    }
+
+    #[must_use]
+    pub const fn cols(&self) -> usize {
+        COLS
+    }
+
+    /// The valid bits of word `word` of a row.
+    const fn mask(word: usize) -> Word {
+        if (word + 1) * BITS <= COLS {
+            Word::MAX
+        } else if word * BITS >= COLS {
+            0
+        } else {
+            (1 << (COLS - word * BITS)) - 1
+        }
+    }
+
+    #[must_use]
+    pub const fn contains(&self, pos: Pos) -> bool {
+        pos.row < ROWS
+            && pos.col < COLS
+            && self.rows[pos.row][pos.col / BITS] & (1 << (pos.col % BITS)) != 0
+    }
+
+    /// Adds `pos`, returning whether it was missing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn insert(&mut self, pos: Pos) -> bool {
+        let missing = !self.contains(pos);
+        self.set(pos, true);
+        missing
+    }
+
+    /// Removes `pos`, returning whether it was present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn remove(&mut self, pos: Pos) -> bool {
+        let present = self.contains(pos);
+        self.set(pos, false);
+        present
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn set(&mut self, pos: Pos, value: bool) {
+        assert!(pos.row < ROWS && pos.col < COLS, "{pos:?} out of bounds");
+
+        let word = &mut self.rows[pos.row][pos.col / BITS];
+        if value {
+            *word |= 1 << (pos.col % BITS);
+        } else {
+            *word &= !(1 << (pos.col % BITS));
+        }
+    }
+
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.rows
+            .as_flattened()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.as_flattened().iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// The cells in the set, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.rows.iter().enumerate().flat_map(|(row, words)| {
+            words.iter().enumerate().flat_map(move |(word, &bits)| {
+                let mut bits = bits;
+                core::iter::from_fn(move || {
+                    if bits == 0 {
+                        return None;
+                    }
+                    let bit = bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    Some(Pos::new(row, word * BITS + bit))
+                })
+            })
+        })
+    }
+
+    /// Moves every cell one step towards `dir`.
+    #[must_use]
+    pub fn shift(&self, dir: Dir, edge: Edge) -> Self {
+        let (dr, dc) = dir.delta();
+
+        let mut result = Self::new();
+        for row in 0..ROWS {
+            let from = match (row.checked_add_signed(-dr), edge) {
+                (Some(from), _) if from < ROWS => from,
+                (_, Edge::Wrap) if ROWS > 0 => (row + ROWS).wrapping_add_signed(-dr) % ROWS,
+                _ => continue,
+            };
+            result.rows[row] = Self::shift_row(&self.rows[from], dc, edge);
+        }
+
+        result
+    }
+
+    fn shift_row(words: &[Word; WORDS], dc: isize, edge: Edge) -> [Word; WORDS] {
+        let mut result = *words;
+        match dc {
+            1 => {
+                let mut carry = 0;
+                for (word, result) in result.iter_mut().enumerate() {
+                    let bits = words[word];
+                    *result = ((bits << 1) | carry) & Self::mask(word);
+                    carry = bits >> (BITS - 1);
+                }
+                if edge == Edge::Wrap && COLS > 0 {
+                    let last = COLS - 1;
+                    result[0] |= (words[last / BITS] >> (last % BITS)) & 1;
+                }
+            }
+            -1 => {
+                let mut carry = 0;
+                for (word, result) in result.iter_mut().enumerate().rev() {
+                    let bits = words[word];
+                    *result = (bits >> 1) | (carry << (BITS - 1));
+                    carry = bits & 1;
+                }
+                if edge == Edge::Wrap && COLS > 0 {
+                    let last = COLS - 1;
+                    result[last / BITS] |= (words[0] & 1) << (last % BITS);
+                }
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    /// Counts, for every cell, its neighbours in the set towards `dirs`.
+    #[must_use]
+    pub fn neighbour_counts(&self, dirs: &[Dir], edge: Edge) -> Counts<ROWS, COLS, WORDS> {
+        let mut planes = [Self::new(); 4];
+        for &dir in dirs {
+            let mut carry = self.shift(-dir, edge);
+            for plane in &mut planes {
+                let next = *plane & carry;
+                *plane ^= carry;
+                carry = next;
+            }
+        }
+
+        Counts { planes }
+    }
+
+    /// The cells of the set connected to `seeds` by steps towards `dirs`.
+    #[must_use]
+    pub fn fill(&self, seeds: &Self, dirs: &[Dir], edge: Edge) -> Self {
+        let mut filled = *seeds & *self;
+        loop {
+            let mut next = filled;
+            for &dir in dirs {
+                next |= filled.shift(dir, edge);
+            }
+            next &= *self;
+
+            if next == filled {
+                return filled;
+            }
+            filled = next;
+        }
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const WORDS: usize> Counts<ROWS, COLS, WORDS> {
+    /// The cells with exactly `n` neighbours: none when `n` is beyond the
+    /// counts the planes can hold.
+    #[must_use]
+    pub fn equal(&self, n: usize) -> BitGrid<ROWS, COLS, WORDS> {
+        if n >> self.planes.len() != 0 {
+            return BitGrid::new();
+        }
+
+        self.planes
+            .iter()
+            .enumerate()
+            .fold(!BitGrid::new(), |result, (bit, &plane)| {
+                result & if n & (1 << bit) != 0 { plane } else { !plane }
+            })
+    }
+
+    /// The cells with an odd number of neighbours.
+    #[must_use]
+    pub const fn odd(&self) -> BitGrid<ROWS, COLS, WORDS> {
+        self.planes[0]
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const WORDS: usize> Default
+    for BitGrid<ROWS, COLS, WORDS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const WORDS: usize> Not for BitGrid<ROWS, COLS, WORDS> {
+    type Output = Self;
+
+    fn not(mut self) -> Self {
+        for words in &mut self.rows {
+            for (word, bits) in words.iter_mut().enumerate() {
+                *bits = !*bits & Self::mask(word);
+            }
+        }
+
+        self
+    }
+}
+
+macro_rules! bit_op {
+    ($op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident) => {
+        impl<const ROWS: usize, const COLS: usize, const WORDS: usize> $assign_op
+            for BitGrid<ROWS, COLS, WORDS>
+        {
+            fn $assign_fn(&mut self, other: Self) {
+                for (bits, other) in self
+                    .rows
+                    .as_flattened_mut()
+                    .iter_mut()
+                    .zip(other.rows.as_flattened())
+                {
+                    bits.$assign_fn(other);
+                }
+            }
+        }
+
+        impl<const ROWS: usize, const COLS: usize, const WORDS: usize> $op
+            for BitGrid<ROWS, COLS, WORDS>
+        {
+            type Output = Self;
+
+            fn $fn(mut self, other: Self) -> Self {
+                self.$assign_fn(other);
+                self
+            }
+        }
+    };
+}
+
+bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<const ROWS: usize, const COLS: usize, const WORDS: usize> fmt::Display
+    for BitGrid<ROWS, COLS, WORDS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                let tile = if self.contains(Pos::new(row, col)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{tile}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize, const WORDS: usize> fmt::Debug
+    for BitGrid<ROWS, COLS, WORDS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    fn grid<const ROWS: usize, const COLS: usize, const WORDS: usize>(
+        map: &str,
+    ) -> BitGrid<ROWS, COLS, WORDS> {
+        let mut grid = BitGrid::new();
+        for (row, line) in map.lines().enumerate() {
+            for (col, tile) in line.chars().enumerate() {
+                grid.set(Pos::new(row, col), tile == '#');
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn test_set() {
+        let mut set = BitGrid::<3, 200, 2>::new();
+
+        assert!(set.insert(Pos::new(1, 150)));
+        assert!(!set.insert(Pos::new(1, 150)));
+        assert!(set.insert(BitGrid::<3, 200, 2>::wrap(-1, -1)));
+        assert_eq!(set.count(), 2);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Pos::new(1, 150), Pos::new(2, 199)]
+        );
+        assert!(!set.contains(Pos::new(3, 0)));
+        assert_eq!((!set).count(), 3 * 200 - 2);
+
+        assert!(set.remove(Pos::new(1, 150)));
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_shift() {
+        let set = grid::<3, 4, 1>("#..#\n....\n.#..");
+
+        assert_eq!(set.shift(Dir::Right, Edge::Clip), grid(".#..\n....\n..#."));
+        assert_eq!(set.shift(Dir::Right, Edge::Wrap), grid("##..\n....\n..#."));
+        assert_eq!(set.shift(Dir::UpLeft, Edge::Clip), grid("....\n#...\n...."));
+        assert_eq!(set.shift(Dir::UpLeft, Edge::Wrap), grid("....\n#...\n..##"));
+
+        let wide = grid::<1, 130, 2>(&("#".to_string() + &".".repeat(127) + "#."));
+        assert_eq!(
+            wide.shift(Dir::Right, Edge::Wrap)
+                .iter()
+                .collect::<Vec<_>>(),
+            [Pos::new(0, 1), Pos::new(0, 129)]
+        );
+        assert_eq!(
+            wide.shift(Dir::Left, Edge::Wrap).iter().collect::<Vec<_>>(),
+            [Pos::new(0, 127), Pos::new(0, 129)]
+        );
+    }
+
+    #[test]
+    fn test_neighbour_counts() {
+        let set = grid::<3, 3, 1>("###\n#..\n...");
+        let counts = set.neighbour_counts(&Dir::ALL, Edge::Clip);
+
+        assert_eq!(counts.equal(0), grid("...\n...\n..#"));
+        assert_eq!(counts.equal(4), grid("...\n.#.\n..."));
+        assert_eq!(counts.equal(20), BitGrid::new());
+        assert_eq!(counts.odd(), grid(".##\n...\n##."));
+    }
+
+    #[test]
+    fn test_fill() {
+        let open = grid::<3, 5, 1>("##.##\n#..##\n.#..#");
+        let mut seed = BitGrid::new();
+        seed.insert(Pos::new(0, 0));
+
+        assert_eq!(
+            open.fill(&seed, &Dir::ORTHOGONAL, Edge::Clip),
+            grid("##...\n#....\n.....")
+        );
+        assert_eq!(
+            open.fill(&seed, &Dir::ALL, Edge::Clip),
+            open & !grid("...##\n...##\n....#")
+        );
+        assert_eq!(open.fill(&seed, &Dir::ORTHOGONAL, Edge::Wrap), open);
+    }
+}
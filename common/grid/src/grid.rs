@@ -0,0 +1,388 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+use crate::{Dir, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("row {row} has {len} cells instead of {cols}")]
+    Ragged { row: usize, len: usize, cols: usize },
+    #[error("{len} cells do not make {rows} rows of {cols}")]
+    Size {
+        len: usize,
+        rows: usize,
+        cols: usize,
+    },
+}
+
+/// A 2D grid of cells stored row by row, owning them in a `Vec` or borrowing
+/// them, e.g. straight from the puzzle input.
+///
+/// Rows are `stride` cells apart, so a borrowed map keeps its newlines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T, S = Vec<T>> {
+    cells: S,
+    rows: usize,
+    cols: usize,
+    stride: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'a> Grid<u8, &'a [u8]> {
+    /// Borrows a map of lines of the same length, ignoring trailing empty
+    /// lines.
+    ///
+    /// # Errors
+    pub fn parse(data: &'a [u8]) -> Result<Self, Error> {
+        let end = data
+            .iter()
+            .rposition(|&tile| tile != b'\n')
+            .map_or(0, |last| last + 1);
+        let data = &data[..end];
+        if data.is_empty() {
+            return Ok(Self::with_stride(data, 0, 0, 1));
+        }
+
+        let cols = data
+            .iter()
+            .position(|&tile| tile == b'\n')
+            .unwrap_or(data.len());
+        let mut rows = 0;
+        for (row, line) in data.split(|&tile| tile == b'\n').enumerate() {
+            if line.len() != cols {
+                return Err(Error::Ragged {
+                    row,
+                    len: line.len(),
+                    cols,
+                });
+            }
+            rows += 1;
+        }
+
+        Ok(Self::with_stride(data, rows, cols, cols + 1))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    #[must_use]
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Self::with_stride(alloc::vec![value; rows * cols], rows, cols, cols)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| Pos::new(row, col)))
+            .map(&mut f)
+            .collect();
+
+        Self::with_stride(cells, rows, cols, cols)
+    }
+
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+}
+
+impl<T, S: AsRef<[T]>> Grid<T, S> {
+    /// Wraps `rows` rows of `cols` cells.
+    ///
+    /// # Errors
+    pub fn from_cells(cells: S, rows: usize, cols: usize) -> Result<Self, Error> {
+        let len = cells.as_ref().len();
+        if len == rows * cols {
+            Ok(Self::with_stride(cells, rows, cols, cols))
+        } else {
+            Err(Error::Size { len, rows, cols })
+        }
+    }
+
+    const fn with_stride(cells: S, rows: usize, cols: usize, stride: usize) -> Self {
+        Self {
+            cells,
+            rows,
+            cols,
+            stride,
+            _marker: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[must_use]
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+
+    #[must_use]
+    pub const fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells.as_ref()[pos.row * self.stride + pos.col])
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {row} out of bounds");
+        &self.cells.as_ref()[row * self.stride..][..self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `col` is out of bounds.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.cols, "column {col} out of bounds");
+        (0..self.rows).map(move |row| &self.row(row)[col])
+    }
+
+    /// The cells from `pos`, excluded, towards `dir` up to the border: with a
+    /// diagonal `dir` it is a diagonal view.
+    pub fn ray(&self, pos: Pos, dir: Dir) -> impl Iterator<Item = (Pos, &T)> {
+        core::iter::successors(Some(pos), move |pos| pos.step(dir))
+            .skip(1)
+            .map_while(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T, S> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().map(|pos| (pos, &self[pos]))
+    }
+
+    #[must_use]
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    #[must_use]
+    pub fn position(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    /// The neighbours of `pos` towards `dirs` inside the grid.
+    pub fn neighbours<'d>(
+        &self,
+        pos: Pos,
+        dirs: &'d [Dir],
+    ) -> impl Iterator<Item = Pos> + use<'d, T, S> {
+        let (rows, cols) = (self.rows, self.cols);
+        dirs.iter()
+            .filter_map(move |&dir| pos.step(dir))
+            .filter(move |pos| pos.row < rows && pos.col < cols)
+    }
+
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T, S> {
+        self.neighbours(pos, &Dir::ORTHOGONAL)
+    }
+
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T, S> {
+        self.neighbours(pos, &Dir::ALL)
+    }
+
+    /// The neighbours of `pos` towards `dirs`, wrapping around the borders.
+    pub fn wrapping_neighbours<'d>(
+        &self,
+        pos: Pos,
+        dirs: &'d [Dir],
+    ) -> impl Iterator<Item = Pos> + use<'d, T, S> {
+        let (rows, cols) = (self.rows, self.cols);
+        dirs.iter()
+            .map(move |&dir| pos.wrapping_step(dir, rows, cols))
+    }
+
+    /// Borrows the cells.
+    #[must_use]
+    pub fn view(&self) -> Grid<T, &[T]> {
+        Grid::with_stride(self.cells.as_ref(), self.rows, self.cols, self.stride)
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_fn(self.rows, self.cols, |pos| f(&self[pos]))
+    }
+
+    #[must_use]
+    pub fn cloned(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.map(T::clone)
+    }
+
+    #[must_use]
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols, self.rows, |Pos { row, col }| {
+            self[Pos::new(col, row)].clone()
+        })
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols, self.rows, |Pos { row, col }| {
+            self[Pos::new(self.rows - 1 - col, row)].clone()
+        })
+    }
+
+    /// Rotates by 90 degrees counterclockwise.
+    #[must_use]
+    pub fn rotate_left(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols, self.rows, |Pos { row, col }| {
+            self[Pos::new(col, self.cols - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T, S: AsRef<[T]> + AsMut<[T]>> Grid<T, S> {
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells.as_mut()[pos.row * self.stride + pos.col])
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.rows, "row {row} out of bounds");
+        &mut self.cells.as_mut()[row * self.stride..][..self.cols]
+    }
+}
+
+impl<T, S: AsRef<[T]>> Index<Pos> for Grid<T, S> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
+
+impl<T, S: AsRef<[T]> + AsMut<[T]>> IndexMut<Pos> for Grid<T, S> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
+
+impl<T: Copy + Into<char>, S: AsRef<[T]>> fmt::Display for Grid<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for &cell in row {
+                write!(f, "{}", cell.into())?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    const MAP: &[u8] = b"#.#\n..#\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(MAP).unwrap();
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.row(1), b"..#");
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"##");
+        assert_eq!(grid.get(Pos::new(0, 3)), None);
+        assert_eq!(grid.position(&b'#'), Some(Pos::new(0, 0)));
+        assert_eq!(grid.find(|&tile| tile == b'.'), Some(Pos::new(0, 1)));
+        assert_eq!(grid.to_string(), core::str::from_utf8(MAP).unwrap());
+
+        assert_eq!(
+            Grid::parse(b"#.#\n.#"),
+            Err(Error::Ragged {
+                row: 1,
+                len: 2,
+                cols: 3
+            })
+        );
+        assert_eq!(Grid::parse(b"").unwrap().positions().count(), 0);
+        assert_eq!(Grid::parse(b"\n\n").unwrap().positions().count(), 0);
+
+        let grid = Grid::parse(b"#.#\n.##\n\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.row(1), b".##");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(MAP).unwrap();
+
+        assert_eq!(
+            grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(),
+            [Pos::new(0, 1), Pos::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+        assert_eq!(
+            grid.wrapping_neighbours(Pos::new(0, 0), &[Dir::Up, Dir::Left])
+                .collect::<Vec<_>>(),
+            [Pos::new(1, 0), Pos::new(0, 2)]
+        );
+        assert_eq!(
+            grid.ray(Pos::new(1, 0), Dir::UpRight)
+                .map(|(pos, &tile)| (pos, tile))
+                .collect::<Vec<_>>(),
+            [(Pos::new(0, 1), b'.')]
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = Grid::from_cells([1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+
+        assert_eq!(grid.transpose().into_vec(), [1, 4, 2, 5, 3, 6]);
+        assert_eq!(grid.rotate_right().into_vec(), [4, 1, 5, 2, 6, 3]);
+        assert_eq!(grid.rotate_left().into_vec(), [3, 6, 2, 5, 1, 4]);
+        assert_eq!(
+            grid.rotate_right().rotate_left().into_vec(),
+            grid.cloned().into_vec()
+        );
+        assert!(Grid::from_cells([1, 2, 3], 2, 2).is_err());
+
+        let mut grid = Grid::new(2, 2, 0);
+        grid[Pos::new(1, 0)] = 1;
+        grid.row_mut(0)[1] = 2;
+        assert_eq!(grid.into_vec(), [0, 2, 1, 0]);
+    }
+}
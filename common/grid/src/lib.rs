@@ -0,0 +1,11 @@
+#![no_std]
+
+extern crate alloc;
+
+mod bitgrid;
+mod grid;
+mod pos;
+
+pub use bitgrid::{BitGrid, Counts, Edge};
+pub use grid::{Error, Grid};
+pub use pos::{Dir, Pos};
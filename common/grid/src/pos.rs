@@ -0,0 +1,196 @@
+use core::ops::Neg;
+
+/// A cell of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A step to one of the 8 neighbours of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Pos {
+    #[must_use]
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The cell `(dr, dc)` away, if not before the first row or column.
+    #[must_use]
+    pub const fn offset(self, (dr, dc): (isize, isize)) -> Option<Self> {
+        match (
+            self.row.checked_add_signed(dr),
+            self.col.checked_add_signed(dc),
+        ) {
+            (Some(row), Some(col)) => Some(Self { row, col }),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn step(self, dir: Dir) -> Option<Self> {
+        self.offset(dir.delta())
+    }
+
+    /// The cell `(dr, dc)` away on a `rows` by `cols` torus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `cols` is 0: an empty torus has no cells.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub const fn wrapping_offset(self, (dr, dc): (isize, isize), rows: usize, cols: usize) -> Self {
+        Self {
+            row: (self.row as isize + dr).rem_euclid(rows as isize) as usize,
+            col: (self.col as isize + dc).rem_euclid(cols as isize) as usize,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `rows` or `cols` is 0.
+    #[must_use]
+    pub const fn wrapping_step(self, dir: Dir, rows: usize, cols: usize) -> Self {
+        self.wrapping_offset(dir.delta(), rows, cols)
+    }
+
+    #[must_use]
+    pub const fn manhattan(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self { row, col }
+    }
+}
+
+impl From<Pos> for (usize, usize) {
+    fn from(Pos { row, col }: Pos) -> Self {
+        (row, col)
+    }
+}
+
+impl Dir {
+    /// The 4 orthogonal directions, clockwise from [`Dir::Up`].
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// The 4 diagonal directions, clockwise from [`Dir::UpRight`].
+    pub const DIAGONAL: [Dir; 4] = [Dir::UpRight, Dir::DownRight, Dir::DownLeft, Dir::UpLeft];
+
+    /// All 8 directions, clockwise from [`Dir::Up`].
+    pub const ALL: [Dir; 8] = [
+        Dir::Up,
+        Dir::UpRight,
+        Dir::Right,
+        Dir::DownRight,
+        Dir::Down,
+        Dir::DownLeft,
+        Dir::Left,
+        Dir::UpLeft,
+    ];
+
+    /// The `(row, column)` delta of a step.
+    #[must_use]
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Dir::Up => (-1, 0),
+            Dir::UpRight => (-1, 1),
+            Dir::Right => (0, 1),
+            Dir::DownRight => (1, 1),
+            Dir::Down => (1, 0),
+            Dir::DownLeft => (1, -1),
+            Dir::Left => (0, -1),
+            Dir::UpLeft => (-1, -1),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        matches!(
+            self,
+            Dir::UpRight | Dir::DownRight | Dir::DownLeft | Dir::UpLeft
+        )
+    }
+
+    /// Turns by 90 degrees clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 2) % 8]
+    }
+
+    /// Turns by 90 degrees counterclockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 6) % 8]
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl Neg for Dir {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.opposite()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let pos = Pos::new(0, 1);
+
+        assert_eq!(pos.step(Dir::Up), None);
+        assert_eq!(pos.step(Dir::DownLeft), Some(Pos::new(1, 0)));
+        assert_eq!(
+            pos.step(Dir::Left).and_then(|pos| pos.step(Dir::Left)),
+            None
+        );
+        assert_eq!(pos.wrapping_step(Dir::UpLeft, 3, 4), Pos::new(2, 0));
+        assert_eq!(
+            Pos::new(2, 3).wrapping_step(Dir::Right, 3, 4),
+            Pos::new(2, 0)
+        );
+        assert_eq!(pos.manhattan(Pos::new(3, 0)), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn test_wrapping_step_empty() {
+        let _ = Pos::new(0, 0).wrapping_step(Dir::Right, 0, 4);
+    }
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::UpLeft.turn_right(), Dir::UpRight);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(-Dir::DownRight, Dir::UpLeft);
+
+        for dir in Dir::ALL {
+            let (dr, dc) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-dr, -dc));
+            assert_eq!(dir.turn_right().delta(), (dc, -dr));
+            assert_eq!(dir.is_diagonal(), dr != 0 && dc != 0);
+        }
+    }
+}
@@ -1,48 +1,155 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
 use core::ptr;
 use core::slice;
 
+mod map;
+mod string;
+mod vec;
+
+pub use map::MemMap;
+pub use string::MemString;
+pub use vec::MemVec;
+
 #[derive(Debug)]
 pub struct Oom;
 
+/// Provides the chunks a growable [`Mem`] chains when the current one is
+/// full.
+pub trait ChunkSource<'m> {
+    /// Returns a chunk of at least `min_len` bytes, or `None` when exhausted.
+    fn chunk(&mut self, min_len: usize) -> Option<&'m mut [MaybeUninit<u8>]>;
+}
+
+impl<'m, F> ChunkSource<'m> for F
+where
+    F: FnMut(usize) -> Option<&'m mut [MaybeUninit<u8>]>,
+{
+    fn chunk(&mut self, min_len: usize) -> Option<&'m mut [MaybeUninit<u8>]> {
+        self(min_len)
+    }
+}
+
+struct Reborrow<'a, 'm>(&'a mut (dyn ChunkSource<'m> + 'm));
+
+impl<'s, 'm: 's> ChunkSource<'s> for Reborrow<'_, 'm> {
+    fn chunk(&mut self, min_len: usize) -> Option<&'s mut [MaybeUninit<u8>]> {
+        self.0.chunk(min_len)
+    }
+}
+
 pub struct Mem<'m> {
     raw: *mut MaybeUninit<u8>,
     len: usize,
+    source: Option<&'m mut dyn ChunkSource<'m>>,
     _marker: PhantomData<&'m [MaybeUninit<u8>]>,
 }
 
+#[cfg(feature = "alloc")]
+struct Heap {
+    chunk_size: usize,
+    // Leaked boxes, freed on drop: moving a `Box` asserts unique access to
+    // its chunk, which would invalidate the slices handed out.
+    chunks: alloc::vec::Vec<*mut [MaybeUninit<u8>]>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'m> ChunkSource<'m> for Heap {
+    fn chunk(&mut self, min_len: usize) -> Option<&'m mut [MaybeUninit<u8>]> {
+        let len = self.chunk_size.max(min_len);
+        let chunk = alloc::boxed::Box::into_raw(alloc::boxed::Box::new_uninit_slice(len));
+        self.chunks.push(chunk);
+
+        // SAFETY: the chunk is a fresh allocation and the heap only keeps its
+        // raw pointer, untouched until the heap is dropped, after the `Mem`
+        // borrowing the chunk for `'m`: the returned slice is its only user.
+        Some(unsafe { &mut *chunk })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for Heap {
+    fn drop(&mut self) {
+        for chunk in self.chunks.drain(..) {
+            // SAFETY: each chunk comes from `Box::into_raw` and is freed once,
+            // when no slice of it is borrowed anymore.
+            drop(unsafe { alloc::boxed::Box::from_raw(chunk) });
+        }
+    }
+}
+
 impl<'m> Mem<'m> {
     /// # Errors
     pub fn with<T>(
         pool: &'m mut [MaybeUninit<u8>],
         f: impl FnOnce(Mem<'m>) -> Result<T, Oom>,
     ) -> Result<T, Oom> {
-        f(Mem {
+        f(Mem::new(pool, None))
+    }
+
+    /// Like [`Mem::with`], chaining the chunks of `source` once `pool` is full.
+    ///
+    /// # Errors
+    pub fn with_source<T>(
+        pool: &'m mut [MaybeUninit<u8>],
+        source: &'m mut dyn ChunkSource<'m>,
+        f: impl FnOnce(Mem<'m>) -> Result<T, Oom>,
+    ) -> Result<T, Oom> {
+        f(Mem::new(pool, Some(source)))
+    }
+
+    fn new(pool: &'m mut [MaybeUninit<u8>], source: Option<&'m mut dyn ChunkSource<'m>>) -> Self {
+        Mem {
             raw: ptr::from_mut(pool).cast(),
             len: pool.len(),
+            source,
             _marker: PhantomData,
-        })
+        }
     }
 
+    /// Free bytes in the current chunk.
     #[must_use]
     pub const fn free(&self) -> usize {
         self.len
     }
 
+    /// Runs `f` on a checkpoint of this memory, rolling back everything `f`
+    /// allocated once it returns: the references handed out inside cannot
+    /// escape the scope. Chunks chained inside the scope are not reused.
+    pub fn scope<R>(&mut self, f: impl for<'s> FnOnce(&mut Mem<'s>) -> R) -> R {
+        let mut source = self.source.take();
+        let mut reborrow = source.as_deref_mut().map(Reborrow);
+
+        let result = f(&mut Mem {
+            raw: self.raw,
+            len: self.len,
+            source: reborrow
+                .as_mut()
+                .map(|source| source as &mut dyn ChunkSource<'_>),
+            _marker: PhantomData,
+        });
+
+        self.source = source;
+
+        result
+    }
+
     /// # Errors
     pub fn alloc_with_scratch<T>(
         &mut self,
         size: usize,
         f: impl FnOnce(&mut Mem<'m>, Mem<'_>) -> Result<&'m mut T, Oom>,
     ) -> Result<&'m mut T, Oom> {
-        let free = self.free();
-        if size > free {
-            return Err(Oom);
+        if size > self.free() {
+            self.grow(size)?;
         }
 
+        let free = self.free();
         let split_at = free - size;
 
         let raw = mem::take(&mut self.raw);
@@ -52,6 +159,7 @@ impl<'m> Mem<'m> {
         let mut main = Mem {
             raw: main,
             len: split_at,
+            source: self.source.take(),
             _marker: core::marker::PhantomData,
         };
         let result = f(
@@ -59,14 +167,20 @@ impl<'m> Mem<'m> {
             Mem {
                 raw: scratch,
                 len: size,
+                source: None,
                 _marker: PhantomData,
             },
         );
 
         let free = main.free();
         let raw = mem::take(&mut main.raw);
+        self.source = main.source.take();
         self.raw = raw;
-        self.len = free + size;
+        self.len = if unsafe { raw.add(free) } == scratch {
+            free + size
+        } else {
+            free
+        };
 
         result
     }
@@ -160,6 +274,7 @@ impl<'m> Mem<'m> {
         let mut main = Mem {
             raw,
             len: free - size * len,
+            source: self.source.take(),
             _marker: PhantomData,
         };
 
@@ -186,6 +301,7 @@ impl<'m> Mem<'m> {
 
         let raw = mem::take(&mut main.raw);
 
+        self.source = main.source.take();
         self.raw = raw;
         self.len = main.free();
 
@@ -196,32 +312,91 @@ impl<'m> Mem<'m> {
         }
     }
 
-    fn align<T>(&mut self, len: usize) -> Result<(), Oom> {
-        let size = mem::size_of::<T>();
-        let free = self.free();
-        if size * len > free {
-            return Err(Oom);
+    /// Extends in place the allocation ending at `end` by `size` bytes, if it
+    /// is the last one and there is room.
+    fn extend(&mut self, end: *mut MaybeUninit<u8>, size: usize) -> bool {
+        if self.raw != end || size > self.free() {
+            return false;
         }
 
-        let align = mem::align_of::<T>();
+        self.raw = unsafe { self.raw.add(size) };
+        self.len -= size;
+
+        true
+    }
 
-        let ptr = self.raw;
+    fn align<T>(&mut self, len: usize) -> Result<(), Oom> {
+        let size = mem::size_of::<T>() * len;
+        let align = mem::align_of::<T>();
 
-        let offset = ptr.align_offset(align);
-        if offset >= free || free - offset < size * len {
-            return Err(Oom);
+        if !self.fits(size, align) {
+            self.grow(size + align - 1)?;
+            if !self.fits(size, align) {
+                return Err(Oom);
+            }
         }
 
+        let offset = self.raw.align_offset(align);
+
         let raw = mem::take(&mut self.raw);
         let raw = unsafe { raw.add(offset) };
 
         self.raw = raw;
-        self.len = free - offset;
+        self.len -= offset;
+
+        Ok(())
+    }
+
+    fn fits(&self, size: usize, align: usize) -> bool {
+        let free = self.free();
+        let offset = self.raw.align_offset(align);
+
+        size <= free && offset <= free && free - offset >= size
+    }
+
+    /// Moves to a new chunk of at least `min_len` bytes, leaving the rest of
+    /// the current one unused.
+    fn grow(&mut self, min_len: usize) -> Result<(), Oom> {
+        let chunk = self
+            .source
+            .as_mut()
+            .and_then(|source| source.chunk(min_len))
+            .ok_or(Oom)?;
+        if chunk.len() < min_len {
+            return Err(Oom);
+        }
+
+        self.raw = ptr::from_mut(chunk).cast();
+        self.len = chunk.len();
 
         Ok(())
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Mem<'_> {
+    /// Runs `f` with a [`Mem`] allocating heap chunks of `chunk_size` bytes,
+    /// or larger when needed, all freed on return.
+    ///
+    /// # Errors
+    pub fn with_heap<T>(
+        chunk_size: usize,
+        f: impl for<'a> FnOnce(Mem<'a>) -> Result<T, Oom>,
+    ) -> Result<T, Oom> {
+        let mut heap = Heap {
+            chunk_size,
+            chunks: alloc::vec::Vec::new(),
+        };
+
+        f(Mem {
+            raw: ptr::null_mut(),
+            len: 0,
+            source: Some(&mut heap),
+            _marker: PhantomData,
+        })
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::cast_possible_truncation)]
 mod test {
@@ -480,4 +655,104 @@ mod test {
         })
         .unwrap();
     }
+
+    #[test]
+    fn test_with_source() {
+        let mut pool = [MaybeUninit::uninit(); 2];
+        let mut extra = [MaybeUninit::uninit(); 8];
+        let mut extra = Some(&mut extra[..]);
+        let mut source = |min_len: usize| extra.take().filter(|chunk| chunk.len() >= min_len);
+
+        let result = Mem::with_source(&mut pool, &mut source, |mut mem| {
+            let x = mem.alloc(1u8)?;
+            let y = mem.alloc(2u8)?;
+            let z = mem.alloc([3u8; 4])?;
+            assert!(mem.alloc([0u8; 8]).is_err());
+            Ok(*x + *y + z.iter().sum::<u8>())
+        })
+        .unwrap();
+
+        assert_eq!(result, 15);
+    }
+
+    #[test]
+    fn test_alloc_with_scratch_grows() {
+        let mut pool = [MaybeUninit::uninit(); 2];
+        let mut extra = [MaybeUninit::uninit(); 8];
+        let mut extra = Some(&mut extra[..]);
+        let mut source = |_| extra.take();
+
+        Mem::with_source(&mut pool, &mut source, |mut mem| {
+            let result = mem.alloc_with_scratch(1, |mem, mut scratch| {
+                let value = scratch.alloc(40u8)?;
+                mem.alloc([*value, 2])
+            })?;
+            assert_eq!(result, &[40, 2]);
+            assert_eq!(mem.free(), 6);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_with_heap() {
+        let result = Mem::with_heap(16, |mut mem| {
+            let values = mem.array_alloc(100, |i| i)?;
+            let mut sum = values.iter().sum::<usize>();
+            for i in 0..10 {
+                sum += *mem.alloc(i)?;
+            }
+            Ok(sum)
+        })
+        .unwrap();
+
+        assert_eq!(result, 4950 + 45);
+    }
+
+    #[test]
+    fn test_scope() {
+        let mut pool = [MaybeUninit::uninit(); 4];
+
+        let result = Mem::with(&mut pool, |mut mem| {
+            let x = mem.alloc(1u8)?;
+
+            let y = mem.scope(|mem| {
+                let arr = mem.array_alloc(3, |i| i as u8 + 2)?;
+                assert_eq!(mem.free(), 0);
+                let z = mem.scope(|mem| mem.alloc(0u8).map(|_| ()));
+                assert!(z.is_err());
+                Ok(arr.iter().sum::<u8>())
+            })?;
+            assert_eq!(mem.free(), 3);
+
+            let z = mem.alloc(4u8)?;
+            Ok(*x + y + *z)
+        })
+        .unwrap();
+
+        assert_eq!(result, 14);
+        assert_eq!(unsafe { pool[1].assume_init_read() }, 4);
+    }
+
+    #[test]
+    fn test_scope_grows() {
+        let mut pool = [MaybeUninit::uninit(); 1];
+        let mut extra = [MaybeUninit::uninit(); 4];
+        let mut extra = Some(&mut extra[..]);
+        let mut source = |_| extra.take();
+
+        Mem::with_source(&mut pool, &mut source, |mut mem| {
+            let sum = mem.scope(|mem| {
+                let arr = mem.array_alloc(2, |i| i as u8 + 1)?;
+                Ok::<_, Oom>(arr.iter().sum::<u8>())
+            })?;
+            assert_eq!(sum, 3);
+            assert_eq!(mem.free(), 1);
+
+            assert!(mem.alloc(0u16).is_err());
+            Ok(())
+        })
+        .unwrap();
+    }
 }
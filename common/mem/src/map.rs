@@ -0,0 +1,278 @@
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem;
+
+use crate::{Mem, Oom};
+
+/// A hash map allocated in a [`Mem`], with open addressing and linear
+/// probing. Entries cannot be removed; the table is moved to a larger
+/// allocation once three quarters full, leaving the old one unused.
+pub struct MemMap<'m, K, V> {
+    slots: &'m mut [Option<(K, V)>],
+    len: usize,
+}
+
+#[derive(Default)]
+struct FxHasher(u64);
+
+impl FxHasher {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    fn add(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self.add(u64::from(byte));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl<'m, K: Hash + Eq, V> MemMap<'m, K, V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            slots: &mut [],
+            len: 0,
+        }
+    }
+
+    /// # Errors
+    pub fn with_capacity(mem: &mut Mem<'m>, capacity: usize) -> Result<Self, Oom> {
+        let mut map = Self::new();
+        map.reserve(mem, capacity)?;
+        Ok(map)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Makes room for at least `additional` more entries.
+    ///
+    /// # Errors
+    pub fn reserve(&mut self, mem: &mut Mem<'m>, additional: usize) -> Result<(), Oom> {
+        let needed = self.len.checked_add(additional).ok_or(Oom)?;
+        if needed * 4 <= self.slots.len() * 3 {
+            return Ok(());
+        }
+
+        let slots = (needed * 4 / 3 + 1)
+            .max(8)
+            .checked_next_power_of_two()
+            .ok_or(Oom)?;
+        let old = mem::replace(&mut self.slots, mem.array_alloc(slots, |_| None)?);
+        for (key, value) in old.iter_mut().filter_map(Option::take) {
+            let index = self.find(&key).unwrap_err();
+            self.slots[index] = Some((key, value));
+        }
+
+        Ok(())
+    }
+
+    /// Inserts `value` at `key`, returning the value it replaces.
+    ///
+    /// # Errors
+    pub fn insert(&mut self, mem: &mut Mem<'m>, key: K, value: V) -> Result<Option<V>, Oom> {
+        if let Some(old) = self.get_mut(&key) {
+            return Ok(Some(mem::replace(old, value)));
+        }
+
+        self.insert_new(mem, key, value)?;
+        Ok(None)
+    }
+
+    /// Returns the value at `key`, inserting the result of `f` if missing.
+    ///
+    /// # Errors
+    pub fn get_or_insert_with(
+        &mut self,
+        mem: &mut Mem<'m>,
+        key: K,
+        f: impl FnOnce() -> V,
+    ) -> Result<&mut V, Oom> {
+        let Ok(index) = self.find(&key) else {
+            return self.insert_new(mem, key, f());
+        };
+
+        match &mut self.slots[index] {
+            Some((_, value)) => Ok(value),
+            None => unreachable!(),
+        }
+    }
+
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key).ok()?;
+        self.slots[index].as_ref().map(|(_, value)| value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key).ok()?;
+        self.slots[index].as_mut().map(|(_, value)| value)
+    }
+
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_ok()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.as_ref().map(|(key, value)| (key, value)))
+    }
+
+    fn insert_new(&mut self, mem: &mut Mem<'m>, key: K, value: V) -> Result<&mut V, Oom> {
+        self.reserve(mem, 1)?;
+
+        let index = self.find(&key).unwrap_err();
+        self.len += 1;
+
+        Ok(&mut self.slots[index].insert((key, value)).1)
+    }
+
+    /// Finds the slot of `key`, or the empty slot where it belongs.
+    #[allow(clippy::cast_possible_truncation)]
+    fn find<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.slots.is_empty() {
+            return Err(0);
+        }
+
+        let mut hasher = FxHasher::default();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let mask = self.slots.len() - 1;
+        let mut index = (hash ^ (hash >> 32)) as usize & mask;
+        loop {
+            match &self.slots[index] {
+                Some((k, _)) if k.borrow() == key => return Ok(index),
+                Some(_) => index = (index + 1) & mask,
+                None => return Err(index),
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for MemMap<'_, K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + fmt::Debug, V: fmt::Debug> fmt::Debug for MemMap<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> Drop for MemMap<'_, K, V> {
+    fn drop(&mut self) {
+        if mem::needs_drop::<(K, V)>() {
+            self.slots.iter_mut().for_each(|slot| drop(slot.take()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::MaybeUninit;
+
+    #[test]
+    fn test_insert() {
+        let mut pool = [MaybeUninit::uninit(); 8192];
+
+        Mem::with(&mut pool, |mut mem| {
+            let mut map = MemMap::new();
+            for i in 0..100u32 {
+                assert_eq!(map.insert(&mut mem, i, i * 2)?, None);
+            }
+            assert_eq!(map.insert(&mut mem, 7, 0)?, Some(14));
+            *map.get_or_insert_with(&mut mem, 100, || 0)? += 1;
+            *map.get_or_insert_with(&mut mem, 100, || 0)? += 1;
+
+            assert_eq!(map.len(), 101);
+            assert_eq!(map.get(&7), Some(&0));
+            assert_eq!(map.get(&99), Some(&198));
+            assert_eq!(map.get(&100), Some(&2));
+            assert!(!map.contains_key(&101));
+            assert_eq!(map.iter().map(|(_, v)| v).sum::<u32>(), 99 * 100 - 14 + 2);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_str_keys() {
+        let mut pool = [MaybeUninit::uninit(); 1024];
+
+        Mem::with(&mut pool, |mut mem| {
+            let mut map = MemMap::with_capacity(&mut mem, 3)?;
+            for word in "a b c b a b".split(' ') {
+                *map.get_or_insert_with(&mut mem, word, || 0)? += 1;
+            }
+
+            assert_eq!(map.get("b"), Some(&3));
+            assert_eq!(map.get("a"), Some(&2));
+            assert_eq!(map.get("d"), None);
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+use core::fmt;
+use core::ops::Deref;
+use core::str;
+
+use crate::{Mem, MemVec, Oom};
+
+/// A growable string allocated in a [`Mem`], see [`MemVec`].
+#[derive(Default)]
+pub struct MemString<'m> {
+    bytes: MemVec<'m, u8>,
+}
+
+impl<'m> MemString<'m> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bytes: MemVec::new(),
+        }
+    }
+
+    /// # Errors
+    pub fn with_capacity(mem: &mut Mem<'m>, capacity: usize) -> Result<Self, Oom> {
+        Ok(Self {
+            bytes: MemVec::with_capacity(mem, capacity)?,
+        })
+    }
+
+    /// # Errors
+    pub fn with_str(mem: &mut Mem<'m>, s: &str) -> Result<Self, Oom> {
+        let mut string = Self::with_capacity(mem, s.len())?;
+        string.push_str(mem, s)?;
+        Ok(string)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.bytes) }
+    }
+
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// # Errors
+    pub fn push(&mut self, mem: &mut Mem<'m>, c: char) -> Result<(), Oom> {
+        self.push_str(mem, c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// # Errors
+    pub fn push_str(&mut self, mem: &mut Mem<'m>, s: &str) -> Result<(), Oom> {
+        self.bytes.extend_from_slice(mem, s.as_bytes())
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.bytes.truncate(self.bytes.len() - c.len_utf8());
+        Some(c)
+    }
+
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Gives up the string, leaving it in the memory.
+    #[must_use]
+    pub fn into_str(self) -> &'m mut str {
+        unsafe { str::from_utf8_unchecked_mut(self.bytes.into_slice()) }
+    }
+}
+
+impl Deref for MemString<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for MemString<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for MemString<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for MemString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for MemString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::MaybeUninit;
+
+    #[test]
+    fn test_push() {
+        let mut pool = [MaybeUninit::uninit(); 16];
+
+        Mem::with(&mut pool, |mut mem| {
+            let mut string = MemString::with_str(&mut mem, "ab")?;
+            string.push(&mut mem, 'é')?;
+            string.push_str(&mut mem, "cd")?;
+            assert_eq!(string, "abécd");
+            assert_eq!(mem.free(), 16 - string.capacity());
+
+            string.pop();
+            assert_eq!(string.pop(), Some('c'));
+            assert_eq!(string.pop(), Some('é'));
+            assert_eq!(string.into_str(), "ab");
+            Ok(())
+        })
+        .unwrap();
+    }
+}
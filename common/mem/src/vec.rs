@@ -0,0 +1,264 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{self, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+use core::slice;
+
+use crate::{Mem, Oom};
+
+/// A growable vector allocated in a [`Mem`].
+///
+/// It grows in place while it is the last allocation of the memory, and is
+/// moved to a larger allocation otherwise, leaving the old one unused.
+pub struct MemVec<'m, T> {
+    ptr: NonNull<T>,
+    len: usize,
+    cap: usize,
+    _marker: PhantomData<&'m mut [T]>,
+}
+
+impl<'m, T> MemVec<'m, T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            len: 0,
+            cap: if mem::size_of::<T>() == 0 {
+                usize::MAX
+            } else {
+                0
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// # Errors
+    pub fn with_capacity(mem: &mut Mem<'m>, capacity: usize) -> Result<Self, Oom> {
+        let mut vec = Self::new();
+        vec.reserve(mem, capacity)?;
+        Ok(vec)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    #[must_use]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Makes room for at least `additional` more elements.
+    ///
+    /// # Errors
+    pub fn reserve(&mut self, mem: &mut Mem<'m>, additional: usize) -> Result<(), Oom> {
+        let needed = self.len.checked_add(additional).ok_or(Oom)?;
+        if needed <= self.cap {
+            return Ok(());
+        }
+
+        let cap = needed.max(self.cap * 2).max(4);
+        if self.cap > 0 {
+            let end = unsafe { self.ptr.as_ptr().add(self.cap) }.cast();
+            for cap in [cap, needed] {
+                if mem.extend(end, (cap - self.cap) * mem::size_of::<T>()) {
+                    self.cap = cap;
+                    return Ok(());
+                }
+            }
+        }
+
+        let (buf, cap) = match mem.array_alloc(cap, |_| MaybeUninit::<T>::uninit()) {
+            Ok(buf) => (buf, cap),
+            Err(Oom) => (mem.array_alloc(needed, |_| MaybeUninit::uninit())?, needed),
+        };
+        let buf = NonNull::from(buf).cast::<T>();
+        unsafe { ptr::copy_nonoverlapping(self.ptr.as_ptr(), buf.as_ptr(), self.len) };
+
+        self.ptr = buf;
+        self.cap = cap;
+
+        Ok(())
+    }
+
+    /// # Errors
+    pub fn push(&mut self, mem: &mut Mem<'m>, value: T) -> Result<(), Oom> {
+        if self.len == self.cap {
+            self.reserve(mem, 1)?;
+        }
+
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+    }
+
+    /// Keeps the first `len` elements, dropping the rest.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail = ptr::from_mut(&mut self.as_mut_slice()[len..]);
+        self.len = len;
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// # Errors
+    pub fn extend_from_slice(&mut self, mem: &mut Mem<'m>, values: &[T]) -> Result<(), Oom>
+    where
+        T: Clone,
+    {
+        self.reserve(mem, values.len())?;
+        for value in values {
+            self.push(mem, value.clone())?;
+        }
+
+        Ok(())
+    }
+
+    /// Gives up the vector, leaving its elements in the memory.
+    #[must_use]
+    pub fn into_slice(self) -> &'m mut [T] {
+        let this = mem::ManuallyDrop::new(self);
+        unsafe { slice::from_raw_parts_mut(this.ptr.as_ptr(), this.len) }
+    }
+}
+
+impl<T> Default for MemVec<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for MemVec<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for MemVec<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MemVec<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T> Drop for MemVec<'_, T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_in_place() {
+        let mut pool = [MaybeUninit::uninit(); 6];
+
+        Mem::with(&mut pool, |mut mem| {
+            let mut vec = MemVec::new();
+            for i in 0..6u8 {
+                vec.push(&mut mem, i)?;
+            }
+            assert_eq!(vec.capacity(), 6);
+            assert_eq!(mem.free(), 0);
+            assert!(vec.push(&mut mem, 6).is_err());
+
+            assert_eq!(vec.pop(), Some(5));
+            assert_eq!(vec.as_slice(), [0, 1, 2, 3, 4]);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_push_moved() {
+        let mut pool = [MaybeUninit::uninit(); 16];
+
+        Mem::with(&mut pool, |mut mem| {
+            let mut a = MemVec::with_capacity(&mut mem, 4)?;
+            a.extend_from_slice(&mut mem, &[1u8, 2, 3, 4])?;
+            let b = mem.alloc(5u8)?;
+
+            a.push(&mut mem, 6)?;
+            assert_eq!(a.capacity(), 8);
+            assert_eq!(mem.free(), 16 - 4 - 1 - 8);
+            assert_eq!(*a, [1, 2, 3, 4, 6]);
+            assert_eq!(*b, 5);
+
+            let a = a.into_slice();
+            a[0] = 0;
+            assert_eq!(a, [0, 2, 3, 4, 6]);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_drop() {
+        struct Counted<'a>(&'a core::cell::Cell<usize>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let dropped = core::cell::Cell::new(0);
+        let mut pool = [MaybeUninit::uninit(); 64];
+
+        Mem::with(&mut pool, |mut mem| {
+            let mut vec = MemVec::new();
+            for _ in 0..3 {
+                vec.push(&mut mem, Counted(&dropped))?;
+            }
+            drop(vec.pop());
+            assert_eq!(dropped.get(), 1);
+            drop(vec);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(dropped.get(), 3);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::modular::mod_mul;
+
+/// Finds the cycle `start` falls into under `step`, with Brent's algorithm,
+/// returning `(tail, length)`: the number of states before the cycle and the
+/// length of the cycle.
+pub fn find_cycle<T: Copy + Eq>(start: T, mut step: impl FnMut(T) -> T) -> (usize, usize) {
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start;
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare;
+            power *= 2;
+            length = 0;
+        }
+        hare = step(hare);
+        length += 1;
+    }
+
+    let (mut tortoise, mut hare) = (start, start);
+    for _ in 0..length {
+        hare = step(hare);
+    }
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(tortoise);
+        hare = step(hare);
+        tail += 1;
+    }
+
+    (tail, length)
+}
+
+/// The sum of `value` over the first `count` states from `start` under
+/// `step`, `start` included, only walking the tail and one cycle.
+pub fn cycle_sum<T: Copy + Eq>(
+    start: T,
+    mut step: impl FnMut(T) -> T,
+    mut value: impl FnMut(T) -> u64,
+    count: u64,
+) -> u64 {
+    let (tail, length) = find_cycle(start, &mut step);
+    let (tail, length) = (tail as u64, length as u64);
+
+    let mut state = start;
+    let mut sum = 0;
+    for _ in 0..tail.min(count) {
+        sum += value(state);
+        state = step(state);
+    }
+    if count <= tail {
+        return sum;
+    }
+
+    let remaining = count - tail;
+    let (mut cycle, mut partial) = (0, 0);
+    for index in 0..length {
+        if index == remaining % length {
+            partial = cycle;
+        }
+        cycle += value(state);
+        state = step(state);
+    }
+
+    sum + cycle * (remaining / length) + partial
+}
+
+/// `base^1 % modulus + ... + base^exp % modulus`.
+///
+/// # Panics
+/// If `modulus` is `0`.
+#[must_use]
+pub fn power_sum(base: u64, exp: u64, modulus: u64) -> u64 {
+    cycle_sum(
+        base % modulus,
+        |power| mod_mul(power, base, modulus),
+        |power| power,
+        exp,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 3
+        let step = |state| if state == 5 { 3 } else { state + 1 };
+        assert_eq!(find_cycle(0, step), (3, 3));
+        assert_eq!(find_cycle(4, step), (0, 3));
+        assert_eq!(find_cycle(7, |state| state), (0, 1));
+    }
+
+    #[test]
+    fn test_cycle_sum() {
+        let step = |state| if state == 5 { 3 } else { state + 1 };
+        let naive = |count| {
+            let mut state = 0;
+            (0..count)
+                .map(|_| {
+                    let value = state;
+                    state = step(state);
+                    value
+                })
+                .sum::<u64>()
+        };
+        for count in 0..20 {
+            assert_eq!(cycle_sum(0, step, |state| state, count), naive(count));
+        }
+    }
+
+    #[test]
+    fn test_power_sum() {
+        assert_eq!(power_sum(2, 7, 5), 19);
+        assert_eq!(power_sum(3, 8, 16), 48);
+        assert_eq!(power_sum(6, 4, 10), 24);
+        assert_eq!(power_sum(9, 1000, 1), 0);
+        assert_eq!(power_sum(2, 0, 5), 0);
+    }
+}
@@ -0,0 +1,114 @@
+use core::ops::{Div, Mul, Rem};
+
+/// The unsigned integers the gcd functions work on.
+pub trait Unsigned:
+    Copy + Eq + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl Unsigned for $ty {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+#[must_use]
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple, `0` if either is `0`.
+#[must_use]
+pub fn lcm<T: Unsigned>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    a / gcd(a, b) * b
+}
+
+/// The gcd of all of `values`, `0` if there are none.
+pub fn gcd_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The lcm of all of `values`, `1` if there are none.
+pub fn lcm_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ONE, lcm)
+}
+
+/// The non-negative `g = gcd(a, b)` with Bézout coefficients `x`, `y` such
+/// that `a * x + b * y == g`.
+///
+/// # Panics
+/// If the gcd is `2^63`, i.e. for `(i64::MIN, 0)` and the like.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (divisor, x, y) = extended_gcd_wide(i128::from(a), i128::from(b));
+    let divisor = i64::try_from(divisor).expect("gcd overflows i64");
+
+    (divisor, x as i64, y as i64)
+}
+
+pub(crate) fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_rem, mut rem) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while rem != 0 {
+        let quotient = old_rem / rem;
+        (old_rem, rem) = (rem, old_rem - quotient * rem);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_rem < 0 {
+        (-old_rem, -old_x, -old_y)
+    } else {
+        (old_rem, old_x, old_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(0usize, 7), 7);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(0u64, 6), 0);
+
+        assert_eq!(gcd_all([12usize, 30, 42]), 6);
+        assert_eq!(lcm_all([2usize, 3, 4, 5]), 60);
+        assert_eq!(gcd_all::<u32>([]), 0);
+        assert_eq!(lcm_all::<u32>([]), 1);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (17, -5), (0, 9), (-9, 0), (0, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(
+                g,
+                i64::try_from(gcd(a.unsigned_abs(), b.unsigned_abs())).unwrap()
+            );
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#![no_std]
+
+mod cycle;
+mod gcd;
+mod modular;
+
+pub use cycle::{cycle_sum, find_cycle, power_sum};
+pub use gcd::{Unsigned, extended_gcd, gcd, gcd_all, lcm, lcm_all};
+pub use modular::{crt, mod_inverse, mod_mul, mod_pow};
@@ -0,0 +1,112 @@
+use crate::gcd::extended_gcd_wide;
+
+/// `a * b % modulus`, without overflowing.
+///
+/// # Panics
+/// If `modulus` is `0`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base ^ exp % modulus`, by squaring.
+///
+/// # Panics
+/// If `modulus` is `0`.
+#[must_use]
+pub const fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        exp >>= 1;
+        base = mod_mul(base, base, modulus);
+    }
+
+    result
+}
+
+/// The `x` in `0..modulus` with `a * x % modulus == 1`, if `a` and `modulus`
+/// are coprime.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    let modulus = i128::from(modulus);
+    let (g, x, _) = extended_gcd_wide(i128::from(a), modulus);
+
+    (g == 1).then(|| x.rem_euclid(modulus) as u64)
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` over all `congruences`,
+/// returning `(x, lcm)` with `x` the smallest solution and `lcm` the combined
+/// modulus.
+///
+/// The moduli don't need to be coprime. Returns `None` if the system has no
+/// solution or the combined modulus doesn't fit in a `u64`.
+///
+/// # Panics
+/// If a modulus is `0`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    let (mut solution, mut lcm) = (0_u64, 1_u64);
+    for (residue, modulus) in congruences {
+        let residue = residue % modulus;
+
+        let (divisor, inverse, _) = extended_gcd_wide(i128::from(lcm), i128::from(modulus));
+        let diff = i128::from(residue) - i128::from(solution);
+        if diff % divisor != 0 {
+            return None;
+        }
+
+        // The next solution is `solution + lcm * times`, with `times` the
+        // solution of `lcm / divisor * times ≡ diff / divisor (mod step)`.
+        let step = (i128::from(modulus) / divisor) as u64;
+        let times = mod_mul(
+            (diff / divisor).rem_euclid(i128::from(step)) as u64,
+            inverse.rem_euclid(i128::from(step)) as u64,
+            step,
+        );
+        let next_lcm = lcm.checked_mul(step)?;
+
+        solution = ((u128::from(solution) + u128::from(lcm) * u128::from(times))
+            % u128::from(next_lcm)) as u64;
+        lcm = next_lcm;
+    }
+
+    Some((solution, lcm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(3, 0, 7), 1);
+        assert_eq!(mod_pow(3, 0, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+        assert_eq!(mod_mul(u64::MAX, u64::MAX, 10), 5);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(12, 5)]), Some((2, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, u64::MAX), (0, u64::MAX - 1)]), None);
+    }
+}
@@ -0,0 +1,358 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The nodes reached by a search, with their cost from the closest start and
+/// the parent they were reached from.
+///
+/// Only settled nodes, i.e. expanded or found as goal, are reported: when the
+/// search stops early the frontier is left out.
+#[derive(Debug, Clone)]
+pub struct Reached<N, C> {
+    nodes: Vec<Entry<N, C>>,
+    index: HashMap<N, usize>,
+    goal: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<N, C> {
+    node: N,
+    cost: C,
+    parent: Option<usize>,
+    settled: bool,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Reached<N, C> {
+    fn new() -> Self {
+        Self {
+            nodes: vec![],
+            index: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Records `node` at `cost`, unless already reached at a cost not worse,
+    /// returning its index.
+    fn reach(&mut self, node: N, cost: C, parent: Option<usize>) -> Option<usize>
+    where
+        C: Ord,
+    {
+        if let Some(&index) = self.index.get(&node) {
+            let entry = &mut self.nodes[index];
+            if entry.settled || entry.cost <= cost {
+                return None;
+            }
+            entry.cost = cost;
+            entry.parent = parent;
+            return Some(index);
+        }
+
+        let index = self.nodes.len();
+        self.index.insert(node.clone(), index);
+        self.nodes.push(Entry {
+            node,
+            cost,
+            parent,
+            settled: false,
+        });
+
+        Some(index)
+    }
+
+    fn settled(&self, node: &N) -> Option<&Entry<N, C>> {
+        self.index
+            .get(node)
+            .map(|&index| &self.nodes[index])
+            .filter(|entry| entry.settled)
+    }
+
+    /// The goal the search stopped at.
+    #[must_use]
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.map(|index| &self.nodes[index].node)
+    }
+
+    #[must_use]
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.map(|index| self.nodes[index].cost)
+    }
+
+    /// The path from a start to the goal, both included.
+    #[must_use]
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.goal.map(|index| self.path_from(index))
+    }
+
+    #[must_use]
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.settled(node).map(|entry| entry.cost)
+    }
+
+    #[must_use]
+    pub fn parent(&self, node: &N) -> Option<&N> {
+        self.settled(node)?
+            .parent
+            .map(|index| &self.nodes[index].node)
+    }
+
+    /// The path from a start to `node`, both included.
+    #[must_use]
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.settled(node)?;
+        Some(self.path_from(self.index[node]))
+    }
+
+    fn path_from(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].node.clone()];
+        while let Some(parent) = self.nodes[index].parent {
+            path.push(self.nodes[parent].node.clone());
+            index = parent;
+        }
+        path.reverse();
+
+        path
+    }
+
+    /// The settled nodes with their cost, in the order they were reached.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes
+            .iter()
+            .filter(|entry| entry.settled)
+            .map(|entry| (&entry.node, entry.cost))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.iter().filter(|entry| entry.settled).count()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The distance map of the settled nodes.
+    #[must_use]
+    pub fn into_costs(self) -> HashMap<N, C> {
+        self.nodes
+            .into_iter()
+            .filter(|entry| entry.settled)
+            .map(|entry| (entry.node, entry.cost))
+            .collect()
+    }
+}
+
+/// Breadth-first search from `start`, stopping at the first node for which
+/// `goal` holds given its distance. Pass `|_, _| false` to reach every node.
+pub fn bfs<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N, usize) -> bool,
+) -> Reached<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    multi_bfs([start], successors, goal)
+}
+
+/// Breadth-first search from all of `starts` at once, so that the cost of a
+/// node is its distance from the closest start.
+pub fn multi_bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N, usize) -> bool,
+) -> Reached<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = Reached::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        queue.extend(reached.reach(start, 0, None));
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let entry = &mut reached.nodes[index];
+        entry.settled = true;
+        let (node, cost) = (entry.node.clone(), entry.cost);
+
+        if goal(&node, cost) {
+            reached.goal = Some(index);
+            break;
+        }
+
+        for next in successors(&node) {
+            queue.extend(reached.reach(next, cost + 1, Some(index)));
+        }
+    }
+
+    reached
+}
+
+/// Dijkstra search from `start` over `(node, cost)` successors, stopping at
+/// the first node for which `goal` holds given its cost. Pass `|_, _| false`
+/// to reach every node.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N, C) -> bool,
+) -> Reached<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// A* search from `start` over `(node, cost)` successors, guided by a
+/// consistent `heuristic` that never overestimates the cost to a goal.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N, C) -> bool,
+) -> Reached<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached = Reached::new();
+    let mut heap = BinaryHeap::new();
+
+    let estimate = heuristic(&start);
+    if let Some(index) = reached.reach(start, C::default(), None) {
+        heap.push(Reverse((estimate, C::default(), index)));
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let entry = &mut reached.nodes[index];
+        if entry.settled || entry.cost < cost {
+            continue;
+        }
+        entry.settled = true;
+        let node = entry.node.clone();
+
+        if goal(&node, cost) {
+            reached.goal = Some(index);
+            break;
+        }
+
+        for (next, step) in successors(&node) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&next);
+            if let Some(next) = reached.reach(next, cost, Some(index)) {
+                heap.push(Reverse((estimate, cost, next)));
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &[&[u8]] = &[b"S.#.", b"..#E", b"#...", b"...."];
+
+    fn neighbours((r, c): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        [(0, 1), (1, 0), (0, -1), (-1, 0)]
+            .into_iter()
+            .filter_map(move |(dr, dc)| {
+                Some((r.checked_add_signed(dr)?, c.checked_add_signed(dc)?))
+            })
+            .filter(|&(r, c)| {
+                MAP.get(r)
+                    .and_then(|row| row.get(c))
+                    .is_some_and(|&tile| tile != b'#')
+            })
+    }
+
+    #[test]
+    fn test_bfs() {
+        let reached = bfs(
+            (0, 0),
+            |&pos| neighbours(pos),
+            |&(r, c), _| MAP[r][c] == b'E',
+        );
+
+        assert_eq!(reached.goal(), Some(&(1, 3)));
+        assert_eq!(reached.goal_cost(), Some(6));
+        let path = reached.goal_path().unwrap();
+        assert_eq!((path.len(), path[0], path[6]), (7, (0, 0), (1, 3)));
+        assert_eq!(reached.cost(&(0, 3)), None);
+
+        let all = bfs((0, 0), |&pos| neighbours(pos), |_, _| false);
+        assert_eq!(all.goal(), None);
+        assert_eq!(all.len(), 13);
+        assert_eq!(all.cost(&(0, 3)), Some(7));
+        assert_eq!(all.parent(&(0, 3)), Some(&(1, 3)));
+        assert_eq!(all.into_costs()[&(3, 3)], 6);
+    }
+
+    #[test]
+    fn test_multi_bfs() {
+        let reached = multi_bfs([(0, 0), (0, 3)], |&pos| neighbours(pos), |_, _| false);
+
+        assert_eq!(reached.cost(&(1, 3)), Some(1));
+        assert_eq!(reached.cost(&(2, 1)), Some(3));
+        assert_eq!(
+            reached.path(&(2, 2)).unwrap(),
+            [(0, 3), (1, 3), (2, 3), (2, 2)]
+        );
+
+        let near = multi_bfs(
+            [(0, 0), (0, 3)],
+            |&pos| neighbours(pos),
+            |_, cost| cost == 3,
+        );
+        assert_eq!(near.goal_cost(), Some(3));
+        assert_eq!(near.cost(&(3, 2)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let weight = |(r, _): (usize, usize)| if r == 2 { 10 } else { 1 };
+        let successors =
+            |&pos: &(usize, usize)| neighbours(pos).map(move |next| (next, weight(next)));
+
+        let reached = dijkstra((0, 0), successors, |&pos, _| pos == (1, 3));
+        assert_eq!(reached.goal_cost(), Some(1 + 1 + 10 + 1 + 1 + 1 + 10 + 1));
+        assert_eq!(
+            reached.goal_path().unwrap()[4..],
+            [(3, 1), (3, 2), (3, 3), (2, 3), (1, 3)]
+        );
+
+        let all = dijkstra((0, 0), successors, |_, _| false);
+        assert_eq!(all.cost(&(2, 2)), Some(22));
+        assert_eq!(all.len(), 13);
+    }
+
+    #[test]
+    fn test_astar() {
+        let target = (1, 3);
+        let manhattan = |&(r, c): &(usize, usize)| r.abs_diff(target.0) + c.abs_diff(target.1);
+
+        let reached = astar(
+            (0, 0),
+            |&pos| neighbours(pos).map(|next| (next, 1)),
+            manhattan,
+            |&pos, _| pos == target,
+        );
+        assert_eq!(reached.goal_cost(), Some(6));
+        assert_eq!(reached.goal_path().map(|path| path.len()), Some(7));
+
+        let unreachable = astar(
+            (0, 0),
+            |&pos| neighbours(pos).map(|next| (next, 1)),
+            manhattan,
+            |&pos, _| pos == (0, 2),
+        );
+        assert_eq!(unreachable.goal(), None);
+    }
+}
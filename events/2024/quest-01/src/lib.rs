@@ -48,6 +48,29 @@ pub fn part_3(data: &[u8]) -> u64 {
         .sum()
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 1,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes())
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,7 +162,7 @@ fn score_fast(laps: usize, plan: &str, terrain: &str) -> u64 {
     let terrain_length = terrain.len();
     let plan_length = plan.len();
 
-    let length = lcm(terrain_length, plan_length);
+    let length = numtheory::lcm(terrain_length, plan_length);
 
     let sums = plan
         .chars()
@@ -199,19 +199,6 @@ fn score_fast(laps: usize, plan: &str, terrain: &str) -> u64 {
     sum + state * remainder as u64 + sums[0..remainder].iter().sum::<i32>() as u64
 }
 
-fn gcd(mut a: usize, mut b: usize) -> usize {
-    while a != 0 {
-        let t = a;
-        a = b % a;
-        b = t;
-    }
-    b
-}
-
-fn lcm(a: usize, b: usize) -> usize {
-    a / gcd(a, b) * b
-}
-
 pub use part_3_slow as part_3;
 
 /// # Panics
@@ -292,6 +279,29 @@ pub fn part_3_fast(data: &[u8], terrain: &[u8]) -> usize {
         .count()
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 7,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes(), ROUND_2_TERRAIN)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data.as_bytes(), ROUND_3_TERRAIN)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
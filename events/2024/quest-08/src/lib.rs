@@ -76,6 +76,36 @@ pub fn part_3<const ACOLYTES: u64, const PLATINUM: u64>(data: &[u8]) -> u64 {
     }
 }
 
+pub struct Solution;
+
+impl Solution {
+    pub const PART_2_ACOLYTES: u64 = 1111;
+    pub const PART_2_MARBLE: u64 = 20_240_000;
+    pub const PART_3_ACOLYTES: u64 = 10;
+    pub const PART_3_PLATINUM: u64 = 202_400_000;
+}
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 8,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2::<{ Solution::PART_2_ACOLYTES }, { Solution::PART_2_MARBLE }>(data.as_bytes())
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3::<{ Solution::PART_3_ACOLYTES }, { Solution::PART_3_PLATINUM }>(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,5 +3,7 @@ fn main() {
     println!("cargo::rerun-if-changed=data/part_2");
     println!("cargo::rerun-if-changed=data/part_3");
     
-    everybody_codes::fetch_parts("data").unwrap();
+    if let Err(err) = everybody_codes::load_parts("data") {
+        println!("cargo::error={err}");
+    }
 }
@@ -196,6 +196,33 @@ pub fn part_3_matrix<const SIZE: usize>(data: &[u8]) -> u32 {
     max - min
 }
 
+pub struct Solution;
+
+impl Solution {
+    pub const PART_3_SIZE: usize = 3;
+}
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 11,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes())
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3::<{ Solution::PART_3_SIZE }>(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unreadable_literal)]
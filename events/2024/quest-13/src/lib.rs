@@ -1,26 +1,18 @@
-use std::collections::{HashSet, HashMap};
-
 type Node = (usize, usize);
 
-fn neighbours<'a>(map: &'a [&'a [u8]], visited: &'a HashSet<Node>, nodes: &'a HashSet<Node>, node: &'a Node) -> impl Iterator<Item = (Node, usize)> + use<'a> {
+fn neighbours<'a>(map: &'a [&'a [u8]], node: Node) -> impl Iterator<Item = (Node, usize)> + use<'a> {
     let a = map[node.0][node.1];
-    
+
     [(0, 1), (0, -1), (1, 0), (-1, 0)]
         .iter()
         .filter_map(move |(dr, dc)| {
-            let r = node.0.checked_add_signed(*dr);
-            let c = node.1.checked_add_signed(*dc);
-
-            match (r, c) {
-                (Some(r), Some(c)) if nodes.contains(&(r, c)) || !visited.contains(&(r, c)) => {
-                    map
-                        .get(r)
-                        .and_then(|row| row.get(c))
-                        .and_then(|&b| if b == b'#' || b == b' ' { None } else { Some(b) })
-                        .map(|b| ((r, c), distance(a, b)))
-                }
-                _ => None,
-            }
+            let r = node.0.checked_add_signed(*dr)?;
+            let c = node.1.checked_add_signed(*dc)?;
+
+            map.get(r)
+                .and_then(|row| row.get(c))
+                .and_then(|&b| if b == b'#' || b == b' ' { None } else { Some(b) })
+                .map(|b| ((r, c), distance(a, b)))
         })
 }
 
@@ -44,14 +36,6 @@ fn distance(a: u8, b: u8) -> usize {
     diff.min(10 - a + b).min(10 - b + a) + 1
 }
 
-fn find_minimum(nodes: &HashSet<Node>, dist: &HashMap<Node, usize>) -> Option<Node> {
-    nodes
-        .iter()
-        .filter_map(|node| dist.get(node).map(|dist| (node, dist)))
-        .min_by_key(|(_, dist)| **dist)
-        .map(|(node, _)| *node)
-}
-
 /// # Panics
 #[must_use]
 pub fn solve<const FROM: u8, const TO: u8>(data: &[u8]) -> usize {
@@ -63,45 +47,13 @@ pub fn solve<const FROM: u8, const TO: u8>(data: &[u8]) -> usize {
         .find_map(|(r, row)| row.iter().position(|tile| *tile == FROM).map(|c| (r, c)))
         .unwrap();
 
-    let mut visited = HashSet::with_capacity(data.len());
-    let mut dist = HashMap::with_capacity(data.len());
-    let mut nodes = HashSet::with_capacity(data.len());
-
-    nodes.insert(start);
-    dist.insert(start, 0);
-
-    while let Some(node) = find_minimum(&nodes, &dist) {
-        if map[node.0][node.1] == TO {
-            return dist[&node];
-        }
-        
-        nodes.remove(&node);
-        visited.insert(node);
-
-        let current_distance = dist[&node];
-        
-        let mut added_nodes = vec![];
-        for (next, distance) in neighbours(&map, &visited, &nodes, &node) {
-            assert!(!visited.contains(&next));
-            
-            added_nodes.push(next);
-            
-            let alt = current_distance + distance;
-            if let Some(current_distance) = dist.get_mut(&next) {
-                if alt < *current_distance {
-                    *current_distance = alt;
-                }
-            } else {
-                dist.insert(next, alt);
-            }
-        }
-
-        for node in added_nodes {
-            nodes.insert(node);
-        }
-    }
-    
-    unreachable!()
+    search::dijkstra(
+        start,
+        |&node| neighbours(&map, node),
+        |&(r, c), _| map[r][c] == TO,
+    )
+    .goal_cost()
+    .unwrap()
 }
 
 #[must_use]
@@ -119,6 +71,29 @@ pub fn part_3(data: &[u8]) -> usize {
     solve::<b'E', b'S'>(data)
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 13,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes())
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,22 +1,10 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint;
 
 use event_2024_15 as event;
 
-fn criterion_benchmark(c: &mut Criterion) {
-    let mut group = c.benchmark_group("event-2024-15");
-    group.bench_function("part 1", |b| {
-        b.iter(|| event::part_1(include_bytes!("../data/part_1")));
+everybody_codes_bench::bench!(event::Solution, parts = 2, |group, data| {
+    group.sample_size(10);
+    group.bench_function("part 3", |b| {
+        b.iter(|| hint::black_box(event::part_3(data.part(3).as_bytes())));
     });
-	group.bench_function("part 2", |b| {
-        b.iter(|| event::part_2(include_bytes!("../data/part_2")));
-    });
-
-    group.sample_size(10).bench_function("part 3", |b| {
-        b.iter(|| event::part_3(include_bytes!("../data/part_3")));
-    });
-
-    group.finish();
-}
-
-criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
+});
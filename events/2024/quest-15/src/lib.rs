@@ -1,15 +1,14 @@
 use std::collections::VecDeque;
 
-type Point = (usize, usize);
+use grid::{Grid, Pos};
 
 /// # Panics
 #[must_use]
 pub fn solve(data: &[u8]) -> usize {
-    let map = data.split(|&c| c == b'\n').collect::<Vec<_>>();
-    let width = map[0].len();
-    let height = map.len();
+    let map = Grid::parse(data).expect("invalid map");
+    let (width, height) = (map.cols(), map.rows());
 
-    let start: Point = (0, map[0].iter().position(|&c| c == b'.').unwrap());
+    let start = Pos::new(0, map.row(0).iter().position(|&c| c == b'.').unwrap());
 
     let target = data.iter().fold(0_u32, |acc, &c| {
         if c.is_ascii_uppercase() {
@@ -19,7 +18,7 @@ pub fn solve(data: &[u8]) -> usize {
         }
     });
 
-    let key = |(r, c), target| target as usize * width * height + r * width + c;
+    let key = |Pos { row, col }, target| target as usize * width * height + row * width + col;
 
     let mut visited = vec![false; height * width * (target as usize + 1)];
     visited[key(start, target)] = true;
@@ -27,36 +26,30 @@ pub fn solve(data: &[u8]) -> usize {
     let mut queue = VecDeque::new();
     queue.push_back((start, 0, target));
 
-    while let Some(((r, c), distance, target)) = queue.pop_front() {
-        if target == 0 && start.0 == r && start.1 == c {
+    while let Some((pos, distance, target)) = queue.pop_front() {
+        if target == 0 && start == pos {
             return distance;
         }
 
-        [(0, 1), (0, -1), (1, 0), (-1, 0)]
-            .iter()
-            .filter_map(
-                |(dr, dc)| match (r.checked_add_signed(*dr), c.checked_add_signed(*dc)) {
-                    (Some(r), Some(c)) => match map.get(r).and_then(|row| row.get(c)) {
-                        _ if visited[key((r, c), target)] => None,
-                        Some(tile) if tile.is_ascii_uppercase() => {
-                            visited[key((r, c), target)] = true;
-
-                            if target & (1 << (tile - b'A')) != 0 {
-                                let target = target ^ (1 << (tile - b'A'));
-                                Some(((r, c), target))
-                            } else {
-                                Some(((r, c), target))
-                            }
-                        }
-                        Some(b'.') => {
-                            visited[key((r, c), target)] = true;
-                            Some(((r, c), target))
-                        }
-                        _ => None,
-                    },
-                    _ => None,
-                },
-            )
+        map.neighbours4(pos)
+            .filter_map(|pos| match map[pos] {
+                _ if visited[key(pos, target)] => None,
+                tile if tile.is_ascii_uppercase() => {
+                    visited[key(pos, target)] = true;
+
+                    if target & (1 << (tile - b'A')) != 0 {
+                        let target = target ^ (1 << (tile - b'A'));
+                        Some((pos, target))
+                    } else {
+                        Some((pos, target))
+                    }
+                }
+                b'.' => {
+                    visited[key(pos, target)] = true;
+                    Some((pos, target))
+                }
+                _ => None,
+            })
             .for_each(|(next, target)| {
                 queue.push_back((next, distance + 1, target));
             });
@@ -69,6 +62,29 @@ pub use solve as part_1;
 pub use solve as part_2;
 pub use solve as part_3;
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 15,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes())
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,8 +2,6 @@ use std::collections::HashMap;
 
 use rayon::prelude::*;
 
-use num::Integer;
-
 fn parse(data: &[u8]) -> (Vec<usize>, Vec<Vec<&[u8]>>) {
     let mut parts = data.split(|&c| c == b'\n');
 
@@ -74,15 +72,12 @@ fn score_eyes(
 fn solve_2(data: &[u8], count: usize) -> usize {
     let (positions, sequences) = parse(data);
 
-    let period = sequences
-        .iter()
-        .zip(positions.iter())
-        .map(|(figure, position)| {
-            assert!(figure.len().gcd(position) == 1);
+    let period = numtheory::lcm_all(sequences.iter().zip(positions.iter()).map(
+        |(figure, &position)| {
+            assert!(numtheory::gcd(figure.len(), position) == 1);
             figure.len()
-        })
-        .reduce(|a, b| a.lcm(&b))
-        .unwrap();
+        },
+    ));
 
     let n = count / period;
 
@@ -168,6 +163,29 @@ pub fn part_3(data: &[u8]) -> String {
     )
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 16,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes())
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unreadable_literal)]
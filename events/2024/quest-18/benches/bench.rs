@@ -1,25 +1,13 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint;
 
 use event_2024_18 as event;
 
-fn criterion_benchmark(c: &mut Criterion) {
-    let mut group = c.benchmark_group("event-2024-18");
-    group.bench_function("part 1", |b| {
-        b.iter(|| event::part_1(include_bytes!("../data/part_1")));
-    });
-    group.bench_function("part 2", |b| {
-        b.iter(|| event::part_2(include_bytes!("../data/part_2")));
-    });
-
+everybody_codes_bench::bench!(event::Solution, parts = 2, |group, data| {
     group.sample_size(10);
     group.bench_function("part 3/par", |b| {
-        b.iter(|| event::part_3_par(include_bytes!("../data/part_3")));
+        b.iter(|| hint::black_box(event::part_3_par(data.part(3).as_bytes())));
     });
     group.bench_function("part 3/nopar", |b| {
-        b.iter(|| event::part_3_nopar(include_bytes!("../data/part_3")));
+        b.iter(|| hint::black_box(event::part_3_nopar(data.part(3).as_bytes())));
     });
-    group.finish();
-}
-
-criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
+});
@@ -1,8 +1,9 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, Ordering};
 
 use rayon::prelude::*;
 
+/// # Panics
 #[must_use]
 fn solve(
     data: &[u8],
@@ -10,33 +11,24 @@ fn solve(
     starts: impl Iterator<Item = (usize, usize)>,
     mut done: impl FnMut(&((usize, usize), u32)) -> Option<u32>,
 ) -> Option<u32> {
-    let mut visited = HashSet::with_capacity(data.len());
-    let mut queue = VecDeque::new();
-
-    for start in starts {
-        visited.insert(start);
-        queue.push_back((start, 0));
-    }
-
-    while let Some(((r, c), time)) = queue.pop_front() {
-        if let Some(result) = done(&((r, c), time)) {
-            return Some(result);
-        }
-        visited.insert((r, c));
-
-        for (dr, dc) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
-            match (r.checked_add_signed(dr), c.checked_add_signed(dc)) {
-                (Some(r), Some(c)) if r < height && c < width && !visited.contains(&(r, c)) => {
-                    if data[r * (width + 1) + c] != b'#' {
-                        queue.push_back(((r, c), time + 1));
-                    }
-                }
-                _ => {}
-            }
-        }
-    }
+    let mut result = None;
+    search::multi_bfs(
+        starts,
+        |&(r, c)| {
+            [(0, 1), (0, -1), (1, 0), (-1, 0)]
+                .into_iter()
+                .filter_map(move |(dr, dc)| {
+                    Some((r.checked_add_signed(dr)?, c.checked_add_signed(dc)?))
+                })
+                .filter(|&(r, c)| r < height && c < width && data[r * (width + 1) + c] != b'#')
+        },
+        |&position, time| {
+            result = done(&(position, u32::try_from(time).unwrap()));
+            result.is_some()
+        },
+    );
 
-    None
+    result
 }
 
 /// # Panics
@@ -203,6 +195,29 @@ pub use part_3_par as part_3;
 pub use solve_1_2 as part_1;
 pub use solve_1_2 as part_2;
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 18,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes())
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
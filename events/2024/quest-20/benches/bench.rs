@@ -1,24 +1,6 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-
 use event_2024_20 as event;
 
-fn criterion_benchmark(c: &mut Criterion) {
-    let mut group = c.benchmark_group("event-2024-20");
-    group.bench_function("part 1", |b| {
-        b.iter(|| event::part_1(include_bytes!("../data/part_1")));
-    });
-    group.bench_function("part 2", |b| {
-        b.iter(|| event::part_2(include_bytes!("../data/part_2")));
-    });
-    group.bench_function("part 3", |b| {
-        b.iter(|| event::part_3(include_bytes!("../data/part_3")));
-    });
-    group.finish();
-}
-
-criterion_group! {
-    name = benches;
-    config = Criterion::default().sample_size(10);
-    targets = criterion_benchmark
-}
-criterion_main!(benches);
+everybody_codes_bench::bench!(event::Solution, parts = 0, |group, data| {
+    group.sample_size(10);
+    everybody_codes_bench::parts::<event::Solution>(&mut group, &data, 3);
+});
@@ -237,114 +237,26 @@ pub fn part_3(data: &[u8]) -> usize {
         .unwrap()
 }
 
-#[cfg(test)]
-mod tests {
-    #![allow(clippy::unreadable_literal)]
-    
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        assert_eq!(
-            1045,
-            part_1(
-                br"#....S....#
-#.........#
-#---------#
-#.........#
-#..+.+.+..#
-#.+-.+.++.#
-#.........#"
-            )
-        );
-    }
+pub struct Solution;
 
-    #[test]
-    fn test_part_2_1() {
-        assert_eq!(
-            24,
-            part_2(
-                br"####S####
-#-.+++.-#
-#.+.+.+.#
-#-.+.+.-#
-#A+.-.+C#
-#.+-.-+.#
-#.+.B.+.#
-#########"
-            )
-        );
-    }
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 20,
+    };
 
-    #[test]
-    fn test_part_2_2() {
-        assert_eq!(
-            78,
-            part_2(
-                br"###############S###############
-#+#..-.+.-++.-.+.--+.#+.#++..+#
-#-+-.+-..--..-+++.+-+.#+.-+.+.#
-#---.--+.--..++++++..+.-.#.-..#
-#+-+.#+-.#-..+#.--.--.....-..##
-#..+..-+-.-+.++..-+..+#-.--..-#
-#.--.A.-#-+-.-++++....+..C-...#
-#++...-..+-.+-..+#--..-.-+..-.#
-#..-#-#---..+....#+#-.-.-.-+.-#
-#.-+.#+++.-...+.+-.-..+-++..-.#
-##-+.+--.#.++--...-+.+-#-+---.#
-#.-.#+...#----...+-.++-+-.+#..#
-#.---#--++#.++.+-+.#.--..-.+#+#
-#+.+.+.+.#.---#+..+-..#-...---#
-#-#.-+##+-#.--#-.-......-#..-##
-#...+.-+..##+..+B.+.#-+-++..--#
-###############################"
-            )
-        );
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
     }
 
-    #[test]
-    fn test_part_2_3() {
-        assert_eq!(
-            206,
-            part_2(
-                br"###############S###############
-#-----------------------------#
-#-------------+++-------------#
-#-------------+++-------------#
-#-------------+++-------------#
-#-----------------------------#
-#-----------------------------#
-#-----------------------------#
-#--A-----------------------C--#
-#-----------------------------#
-#-----------------------------#
-#-----------------------------#
-#-----------------------------#
-#-----------------------------#
-#-----------------------------#
-#--------------B--------------#
-#-----------------------------#
-#-----------------------------#
-###############################"
-            )
-        );
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data.as_bytes())
     }
 
-    #[test]
-    #[ignore = "bho"]
-    fn test_part_3() {
-        assert_eq!(
-            768790,
-            part_3(
-                br"#......S......#
-#-...+...-...+#
-#.............#
-#..+...-...+..#
-#.............#
-#-...-...+...-#
-#.............#
-#..#...+...+..#"
-            )
-        );
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data.as_bytes())
     }
 }
+
+everybody_codes_quest::register!(Solution);
+everybody_codes_quest::sample_tests!(Solution);
@@ -79,3 +79,19 @@ pub fn part_1(data: &[u8]) -> String {
         .map(|row| row.iter().collect::<String>() + "\n")
         .collect::<String>()
 }
+
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2024,
+        quest: 21,
+    };
+    const PARTS: u8 = 1;
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data.as_bytes())
+    }
+}
+
+everybody_codes_quest::register!(Solution);
@@ -1,8 +1 @@
-use event_2024_21 as event;
-
-fn main() {
-    println!(
-        "part 1: {}",
-        event::part_1(include_bytes!("../data/part_1"))
-    );
-}
+everybody_codes_quest::main!(event_2024_21::ENTRY);
@@ -74,6 +74,29 @@ pub fn part_3(data: &str) -> &str {
     list[0]
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 1,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,22 +1,9 @@
-use criterion::{Criterion, criterion_group, criterion_main};
-
 use event_2025_02 as event;
 
-fn criterion_benchmark(c: &mut Criterion) {
-    let mut group = c.benchmark_group("event-2025-02");
-    group.bench_function("part 1", |b| {
-        b.iter(|| event::part_1(include_str!("../data/part_1")));
-    });
-    group.bench_function("part 2", |b| {
-        b.iter(|| event::part_2(include_str!("../data/part_2")));
-    });
-    group.bench_function("part 3", |b| {
-        b.iter(|| event::part_3(include_str!("../data/part_3")));
-    });
-
+everybody_codes_bench::bench!(event::Solution, |group, data| {
     #[cfg(feature = "simd")]
     {
-        let a = event::Complex::parse(include_str!("../data/part_2")).unwrap();
+        let a = event::Complex::parse(data.part(2)).unwrap();
         group.bench_function("simple 10", |b| {
             b.iter(|| event::simple::mandelbrot::<1_000, 10>(a));
         });
@@ -39,7 +26,7 @@ fn criterion_benchmark(c: &mut Criterion) {
             b.iter(|| event::simd::mandelbrot::<64, 1_000, 10>(a));
         });
 
-        let a = event::Complex::parse(include_str!("../data/part_3")).unwrap();
+        let a = event::Complex::parse(data.part(3)).unwrap();
         group.bench_function("simple 1", |b| {
             b.iter(|| event::simple::mandelbrot::<1_000, 1>(a));
         });
@@ -62,9 +49,4 @@ fn criterion_benchmark(c: &mut Criterion) {
             b.iter(|| event::simd::mandelbrot::<64, 1_000, 1>(a));
         });
     }
-
-    group.finish();
-}
-
-criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
+});
@@ -1,5 +1,3 @@
-use gcd::Gcd;
-
 trait Chunks2<I: Iterator> {
     fn chunks2(self) -> Chunks2Impl<I>;
 }
@@ -68,7 +66,7 @@ pub fn part_3(data: &str) -> u64 {
             let n = n * gear_a;
             let d = d * gear_b;
 
-            let div = n.gcd(d);
+            let div = numtheory::gcd(n, d);
 
             (n / div, d / div)
         });
@@ -76,6 +74,29 @@ pub fn part_3(data: &str) -> u64 {
     n / d
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 4,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -38,6 +38,8 @@ where
 {
 }
 
+const CHUNK_SIZE: usize = 1024;
+
 #[derive(Debug)]
 struct Level {
     left: Option<u8>,
@@ -61,16 +63,13 @@ fn fishbone<'m, 'a>(
 ) -> Result<(&'a str, &'m mut [&'m mut Level]), mem::Oom> {
     let (id, numbers) = data.split_once(':').expect("invalid data");
 
-    let mut len = 0;
-    let mut fishbone: [Option<&mut Level>; 64] = const { [const { None }; 64] };
+    let mut fishbone = mem::MemVec::<&mut Level>::new();
     for number in numbers
         .split(',')
         .map(|number| number.parse().expect("invalid number"))
     {
         let mut found = false;
-        for level in &mut fishbone[0..len] {
-            let level = level.as_mut().unwrap();
-
+        for level in fishbone.iter_mut() {
             if level.left.is_none() && number < level.center {
                 level.left = Some(number);
                 found = true;
@@ -88,65 +87,40 @@ fn fishbone<'m, 'a>(
                 center: number,
                 right: None,
             })?;
-            fishbone[len] = Some(level);
-            len += 1;
+            fishbone.push(mem, level)?;
         }
     }
 
-    let result = mem.array_alloc(len, |i| fishbone[i].take().unwrap())?;
+    Ok((id, fishbone.into_slice()))
+}
 
-    Ok((id, result))
+fn quality(fishbone: &[&mut Level]) -> u64 {
+    fishbone.iter().fold(0, |acc, level| {
+        acc * (if level.center < 10 { 10 } else { 100 }) + u64::from(level.center)
+    })
 }
 
 /// # Panics
 fn sword<'m>(mem: &mut mem::Mem<'m>, data: &str) -> Result<(u64, &'m mut [u64], u64), mem::Oom> {
-    let mut pool = [core::mem::MaybeUninit::uninit(); const {
-        core::mem::size_of::<Level>() * 64 + core::mem::size_of::<&mut [Level]>() * 64
-    }];
-
-    mem::Mem::with(&mut pool, |mut scratch| {
+    mem::Mem::with_heap(CHUNK_SIZE, |mut scratch| {
         let (id, fishbone) = fishbone(&mut scratch, data)?;
 
-        let mut len = 0;
-        let mut levels = [0u64; 16];
-        let mut quality = 0;
-        for Level {
-            left,
-            center,
-            right,
-        } in fishbone
-        {
-            levels[len] = make_level(*left, *center, *right);
-            len += 1;
-
-            quality = quality * (if *center < 10 { 10 } else { 100 }) + u64::from(*center);
-        }
-
-        let levels = mem.array_alloc(len, |i| levels[i])?;
+        let levels = mem.array_alloc(fishbone.len(), |i| {
+            let level = &fishbone[i];
+            make_level(level.left, level.center, level.right)
+        })?;
 
-        Ok((quality, levels, id.parse().expect("invalid id")))
+        Ok((quality(fishbone), levels, id.parse().expect("invalid id")))
     })
 }
 
 /// # Panics
 #[must_use]
 fn quality_value(data: &str) -> u64 {
-    let mut pool = [core::mem::MaybeUninit::uninit(); const {
-        core::mem::size_of::<Level>() * 64 + core::mem::size_of::<&mut [Level]>() * 64
-    }];
-
-    mem::Mem::with(&mut pool, |mut mem| {
+    mem::Mem::with_heap(CHUNK_SIZE, |mut mem| {
         let (_, fishbone) = fishbone(&mut mem, data)?;
 
-        Ok(fishbone.iter().fold(
-            0,
-            |acc,
-             Level {
-                 left: _,
-                 center,
-                 right: _,
-             }| { acc * (if *center < 10 { 10 } else { 100 }) + u64::from(*center) },
-        ))
+        Ok(quality(fishbone))
     })
     .unwrap()
 }
@@ -168,14 +142,12 @@ pub fn part_2(data: &str) -> u64 {
 }
 
 /// # Panics
-#[allow(clippy::large_stack_arrays)]
 #[must_use]
 pub fn part_3(data: &str) -> u64 {
-    let mut pool =
-        [core::mem::MaybeUninit::uninit(); const { core::mem::size_of::<u64>() * 8 * 1024 }];
-
-    mem::Mem::with(&mut pool, |mut mem| {
-        let swords = mem.array_collect_alloc(512, data.lines(), |mem, line| sword(mem, line))?;
+    mem::Mem::with_heap(core::mem::size_of::<u64>() * 1024, |mut mem| {
+        let swords = mem.array_collect_alloc(data.lines().count(), data.lines(), |mem, line| {
+            sword(mem, line)
+        })?;
 
         swords.sort_unstable();
 
@@ -189,6 +161,29 @@ pub fn part_3(data: &str) -> u64 {
     .unwrap()
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 5,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,6 +68,34 @@ pub fn part_3<const REPEAT: usize, const DISTANCE: usize>(data: &str) -> usize {
         .sum()
 }
 
+pub struct Solution;
+
+impl Solution {
+    pub const PART_3_REPEAT: usize = 1000;
+    pub const PART_3_DISTANCE: usize = 1000;
+}
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 6,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3::<{ Solution::PART_3_REPEAT }, { Solution::PART_3_DISTANCE }>(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,6 +63,35 @@ pub fn part_3<const NAILS: u32>(data: &str) -> usize {
         .unwrap()
 }
 
+pub struct Solution;
+
+impl Solution {
+    pub const PART_1_NAILS: u8 = 32;
+    pub const PART_2_NAILS: u32 = 256;
+    pub const PART_3_NAILS: u32 = 256;
+}
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 8,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1::<{ Solution::PART_1_NAILS }>(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2::<{ Solution::PART_2_NAILS }>(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3::<{ Solution::PART_3_NAILS }>(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,59 +1,8 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
+use disjoint_set::DisjointSet;
 use rayon::prelude::*;
 
-struct MergeSets {
-    id: usize,
-    nodes: HashMap<usize, usize>,
-    sets: HashMap<usize, HashSet<usize>>,
-}
-
-impl MergeSets {
-    fn new(nodes: usize) -> Self {
-        Self {
-            id: nodes,
-            nodes: (0..nodes).map(|node| (node, node)).collect(),
-            sets: (0..nodes)
-                .map(|node| {
-                    let mut set = HashSet::with_capacity(nodes);
-                    set.insert(node);
-                    (node, set)
-                })
-                .collect(),
-        }
-    }
-
-    fn merge(&mut self, a: usize, b: usize) {
-        let set_a_id = self.nodes[&a];
-        let set_b_id = self.nodes[&b];
-        if set_a_id == set_b_id {
-            return;
-        }
-
-        let mut union = self.sets.remove(&set_a_id).unwrap();
-        for element in self.sets.remove(&set_b_id).unwrap() {
-            union.insert(element);
-        }
-
-        let id = self.new_id();
-        for element in &union {
-            self.nodes.insert(*element, id);
-        }
-
-        self.sets.insert(id, union);
-    }
-
-    fn new_id(&mut self) -> usize {
-        let id = self.id;
-        self.id += 1;
-        id
-    }
-
-    fn max(&self) -> &HashSet<usize> {
-        self.sets.iter().max_by_key(|(_, set)| set.len()).unwrap().1
-    }
-}
-
 fn similarity(first: &[u8], second: &[u8]) -> u128 {
     let mut result = 0u128;
     for (i, (first, second)) in first.iter().zip(second).enumerate() {
@@ -156,7 +105,7 @@ pub fn part_3(data: &str) -> usize {
     }
 
     let merge_sets = if dnas.len() > 3 {
-        let merge_sets = std::sync::Mutex::new(MergeSets::new(dnas.len()));
+        let merge_sets = std::sync::Mutex::new(DisjointSet::new(dnas.len()));
         (0..dnas.len()).into_par_iter().for_each(|candidate| {
             for first_parent in 0..dnas.len() - 1 {
                 if first_parent == candidate {
@@ -171,8 +120,8 @@ pub fn part_3(data: &str) -> usize {
                     if table[&(candidate, first_parent)] | table[&(candidate, second_parent)]
                         == mask
                     {
-                        merge_sets.lock().unwrap().merge(candidate, first_parent);
-                        merge_sets.lock().unwrap().merge(candidate, second_parent);
+                        merge_sets.lock().unwrap().union(candidate, first_parent);
+                        merge_sets.lock().unwrap().union(candidate, second_parent);
                         return;
                     }
                 }
@@ -181,7 +130,7 @@ pub fn part_3(data: &str) -> usize {
 
         merge_sets.into_inner().unwrap()
     } else {
-        let mut merge_sets = MergeSets::new(dnas.len());
+        let mut merge_sets = DisjointSet::new(dnas.len());
         for candidate in 0..dnas.len() {
             'outher: for first_parent in 0..dnas.len() - 1 {
                 if first_parent == candidate {
@@ -196,8 +145,8 @@ pub fn part_3(data: &str) -> usize {
                     if table[&(candidate, first_parent)] | table[&(candidate, second_parent)]
                         == mask
                     {
-                        merge_sets.merge(candidate, first_parent);
-                        merge_sets.merge(candidate, second_parent);
+                        merge_sets.union(candidate, first_parent);
+                        merge_sets.union(candidate, second_parent);
                         break 'outher;
                     }
                 }
@@ -206,9 +155,34 @@ pub fn part_3(data: &str) -> usize {
         merge_sets
     };
 
-    merge_sets.max().iter().map(|index| index + 1).sum()
+    merge_sets.largest().map_or(0, |largest| {
+        merge_sets.members(largest).map(|index| index + 1).sum()
+    })
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 9,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,21 +247,4 @@ mod tests {
             36,
         );
     }
-
-    #[test]
-    fn merge_test() {
-        let mut merge_set = MergeSets::new(500);
-
-        merge_set.merge(1, 2);
-        merge_set.merge(3, 4);
-        merge_set.merge(1, 3);
-        merge_set.merge(10, 11);
-        merge_set.merge(11, 12);
-        merge_set.merge(4, 11);
-
-        let mut set = merge_set.max().iter().copied().collect::<Vec<_>>();
-        set.sort_unstable();
-
-        assert_eq!(&set, &[1, 2, 3, 4, 10, 11, 12]);
-    }
 }
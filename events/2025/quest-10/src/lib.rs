@@ -7,6 +7,34 @@ pub use part_2::solve as part_2;
 mod part_3;
 pub use part_3::solve as part_3;
 
+pub struct Solution;
+
+impl Solution {
+    pub const PART_1_MOVES: usize = 4;
+    pub const PART_2_MOVES: usize = 20;
+}
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 10,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1::<{ Solution::PART_1_MOVES }>(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2::<{ Solution::PART_2_MOVES }>(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 const JUMPS: [(isize, isize); 8] = [
     (-1, -2),
     (-2, -1),
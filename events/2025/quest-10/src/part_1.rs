@@ -37,11 +37,10 @@ pub fn solve<const MOVES: usize>(data: &str) -> u32 {
                 (Some(new_row), Some(new_column))
                     if new_row < rows
                         && new_column < columns
-                        && !visited.contains(&(new_row, new_column)) =>
+                        && !visited.contains(&(new_row, new_column))
+                        && moves < MOVES =>
                 {
-                    if moves < MOVES {
-                        queue.push_back(((new_row, new_column), moves + 1));
-                    }
+                    queue.push_back(((new_row, new_column), moves + 1));
                 }
                 _ => {}
             }
@@ -98,26 +98,13 @@ fn unique_sequences(
                         (Some(new_row), Some(new_column))
                             if new_row < board.rows && new_column < board.columns =>
                         {
-                            board
-                                .eat(sheeps, (new_row, new_column))
-                                .map(|sheeps| {
-                                    unique_sequences(
-                                        memoize,
-                                        board,
-                                        Turn::Sheeps,
-                                        sheeps,
-                                        (new_row, new_column),
-                                    )
-                                })
-                                .unwrap_or_else(|| {
-                                    unique_sequences(
-                                        memoize,
-                                        board,
-                                        Turn::Sheeps,
-                                        sheeps,
-                                        (new_row, new_column),
-                                    )
-                                })
+                            unique_sequences(
+                                memoize,
+                                board,
+                                Turn::Sheeps,
+                                board.eat(sheeps, (new_row, new_column)).unwrap_or(sheeps),
+                                (new_row, new_column),
+                            )
                         }
                         _ => 0,
                     }
@@ -1,11 +1,7 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::{HashMap, HashSet};
 
 const ORIGIN: (isize, isize) = (0, 0);
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
-struct Info(usize, (usize, usize));
-
 fn manhattan((x1, y1): (isize, isize), (x2, y2): (isize, isize)) -> usize {
     (x1 - x2).unsigned_abs() + (y1 - y2).unsigned_abs()
 }
@@ -107,51 +103,33 @@ fn solve(data: &str) -> usize {
     board[start.1][start.0] = true;
     board[end.1][end.0] = true;
 
-    let mut scores = HashMap::with_capacity(xs.len() * ys.len());
-    scores.insert(start, 0);
-
-    let mut heap = BinaryHeap::with_capacity(xs.len() * ys.len());
-    heap.push(Reverse(Info(manhattan(ORIGIN, current), start)));
-    while let Some(Reverse(Info(_f, (x, y)))) = heap.pop() {
-        if (x, y) == end {
-            return scores[&(x, y)];
-        }
-
-        let xx = idx2xs[&x];
-        let yy = idx2ys[&y];
-
-        let current_score = scores.get(&(x, y)).copied().unwrap_or(usize::MAX);
-        for (dx, dy) in [(-1, 0), (1, 0), (0, 1), (0, -1)] {
-            let Some(new_x) = x.checked_add_signed(dx) else {
-                continue;
-            };
-            let Some(new_y) = y.checked_add_signed(dy) else {
-                continue;
-            };
-            if !board
-                .get(new_y)
-                .and_then(|row| row.get(new_x))
-                .copied()
-                .unwrap_or_default()
-            {
-                continue;
-            }
-
-            let new_xx = idx2xs[&new_x];
-            let new_yy = idx2ys[&new_y];
-
-            let score = current_score.saturating_add(manhattan((xx, yy), (new_xx, new_yy)));
-            if score < scores.get(&(new_x, new_y)).copied().unwrap_or(usize::MAX) {
-                scores.insert((new_x, new_y), score);
-                heap.push(Reverse(Info(
-                    score + manhattan(ORIGIN, (new_xx, new_yy)),
-                    (new_x, new_y),
-                )));
-            }
-        }
-    }
-
-    unreachable!()
+    search::astar(
+        start,
+        |&(x, y): &(usize, usize)| {
+            let position = (idx2xs[&x], idx2ys[&y]);
+            [(-1, 0), (1, 0), (0, 1), (0, -1)]
+                .into_iter()
+                .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+                .filter(|&(x, y)| {
+                    board
+                        .get(y)
+                        .and_then(|row| row.get(x))
+                        .copied()
+                        .unwrap_or_default()
+                })
+                .map(|next| {
+                    (
+                        next,
+                        manhattan(position, (idx2xs[&next.0], idx2ys[&next.1])),
+                    )
+                })
+                .collect::<Vec<_>>()
+        },
+        |&(x, y)| manhattan(ORIGIN, (idx2xs[&x], idx2ys[&y])),
+        |&node, _| node == end,
+    )
+    .goal_cost()
+    .expect("No path to the origin")
 }
 
 /// # Panics
@@ -172,6 +150,29 @@ pub fn part_3(data: &str) -> usize {
     solve(data)
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 15,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,7 +44,8 @@ pub fn part_2(data: &str) -> u64 {
 #[allow(
     clippy::cast_precision_loss,
     clippy::cast_possible_truncation,
-    clippy::cast_sign_loss
+    clippy::cast_sign_loss,
+    clippy::manual_midpoint
 )]
 pub fn part_3(data: &str) -> u64 {
     #[allow(clippy::unreadable_literal)]
@@ -70,6 +71,29 @@ pub fn part_3(data: &str) -> u64 {
     low
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 16,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,7 +115,7 @@ mod tests {
     fn test_part_3() {
         assert_eq!(
             part_3("1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2"),
-            94439495762954
+            94_439_495_762_954
         );
     }
 }
@@ -121,14 +121,15 @@ fn energy(
 
 fn find_max(plants: &[Plant]) -> i64 {
     fn find_max_r(
+        mem: &mut mem::Mem<'_>,
         plants: &[Plant],
-        mut activation: Vec<cmp::Ordering>,
+        activation: &mut [cmp::Ordering],
         plant: &Plant,
         mut index: usize,
-    ) -> i64 {
+    ) -> Result<i64, mem::Oom> {
         loop {
             if index == activation.len() {
-                return energy(plants, OrdActivation(&activation), plant);
+                return Ok(energy(plants, OrdActivation(activation), plant));
             }
 
             match activation[index] {
@@ -137,12 +138,15 @@ fn find_max(plants: &[Plant]) -> i64 {
                 }
                 cmp::Ordering::Equal => {
                     activation[index] = cmp::Ordering::Greater;
-                    let a = find_max_r(plants, activation.clone(), plant, index);
+                    let a = mem.scope(|mem| {
+                        let activation = mem.array_alloc(activation.len(), |i| activation[i])?;
+                        find_max_r(mem, plants, activation, plant, index)
+                    })?;
 
                     activation[index] = cmp::Ordering::Less;
-                    let b = find_max_r(plants, activation, plant, index);
+                    let b = find_max_r(mem, plants, activation, plant, index)?;
 
-                    return a.max(b);
+                    return Ok(a.max(b));
                 }
             }
         }
@@ -175,7 +179,11 @@ fn find_max(plants: &[Plant]) -> i64 {
         })
         .collect::<Vec<_>>();
 
-    find_max_r(plants, activation, plants.last().unwrap(), 0)
+    mem::Mem::with_heap(activation.len() * activation.len(), |mut mem| {
+        let activation = mem.array_alloc(activation.len(), |i| activation[i])?;
+        find_max_r(&mut mem, plants, activation, plants.last().unwrap(), 0)
+    })
+    .unwrap()
 }
 
 /// # Panics
@@ -260,6 +268,29 @@ pub fn part_3(data: &str) -> i64 {
         .sum()
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Event {
+        year: 2025,
+        quest: 18,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -18,23 +18,6 @@ fn join(mut result: u64, mut cur: u64, mut mul: u64) -> (u64, u64) {
     }
 }
 
-fn mod_pow(mut n: u64, mut exp: u64, modulus: u64) -> u64 {
-    if modulus == 1 {
-        return 0;
-    }
-
-    let mut result = 1;
-    n %= modulus;
-    while exp > 0 {
-        if exp % 2 == 1 {
-            result = (result * n) % modulus;
-        }
-        exp >>= 1;
-        n = (n * n) % modulus;
-    }
-    result
-}
-
 fn eni_1(n: u64, exp: u64, modulus: u64) -> u64 {
     let mut cur = 1;
     let mut result = 0;
@@ -50,7 +33,7 @@ fn eni_2(n: u64, exp: u64, modulus: u64) -> u64 {
     if exp < 5 {
         eni_1(n, exp, modulus)
     } else {
-        let mut cur = mod_pow(n, exp - 5, modulus);
+        let mut cur = numtheory::mod_pow(n, exp - 5, modulus);
         let mut result = 0;
         let mut mul = 1;
         for _ in 0..5 {
@@ -61,33 +44,6 @@ fn eni_2(n: u64, exp: u64, modulus: u64) -> u64 {
     }
 }
 
-fn eni_3(n: u64, exp: u64, modulus: u64) -> u64 {
-    let mut sum = 0;
-    let mut seen_at = vec![None; modulus as usize];
-    let mut partial_sums = vec![sum];
-    let mut cur = 1;
-    while partial_sums.len() < exp as usize {
-        cur = (cur * n) % modulus;
-        if let Some(cycle_start) = seen_at[cur as usize] {
-            let cycle_length = partial_sums.len() - cycle_start;
-            let cycle_sum = sum + cur - partial_sums[cycle_start];
-            let remaining = exp - partial_sums.len() as u64 + 1;
-            let cycles = remaining / cycle_length as u64;
-
-            sum += cycle_sum * cycles
-                + partial_sums[cycle_start + remaining as usize % cycle_length - 1]
-                - partial_sums[cycle_start - 1];
-
-            break;
-        }
-
-        sum += cur;
-        seen_at[cur as usize] = Some(partial_sums.len());
-        partial_sums.push(sum);
-    }
-    sum
-}
-
 impl<'a> FromIterator<&'a str> for Part {
     fn from_iter<II: IntoIterator<Item = &'a str>>(ii: II) -> Self {
         Self(
@@ -152,9 +108,32 @@ pub fn part_2(data: &str) -> u64 {
 
 #[must_use]
 pub fn part_3(data: &str) -> u64 {
-    solve_rayon(data, eni_3)
+    solve_rayon(data, numtheory::power_sum)
+}
+
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 1,
+        quest: 1,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
 }
 
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unreadable_literal)]
@@ -179,12 +158,6 @@ A=8 B=8 C=8 X=6 Y=9 Z=6 M=16";
         assert_eq!(eni_2(3, 8, 16), 111931);
     }
 
-    #[test]
-    fn test_eni_3() {
-        assert_eq!(eni_3(2, 7, 5), 19);
-        assert_eq!(eni_3(3, 8, 16), 48);
-    }
-
     #[test]
     fn test_part_2_1() {
         let data = r"A=4 B=4 C=6 X=3 Y=14 Z=15 M=11
@@ -185,7 +185,7 @@ pub fn part_3(data: &str) -> String {
         .max_by_key(|(level, s)| (s.len(), std::cmp::Reverse(*level)))
         .unwrap()
         .1
-        .to_string()
+        .clone()
         + right_nodes
             .iter()
             .max_by_key(|(level, s)| (s.len(), std::cmp::Reverse(*level)))
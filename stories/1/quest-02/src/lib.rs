@@ -4,6 +4,29 @@ mod btree2;
 pub use btree::{part_1, part_2};
 pub use btree2::part_3;
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 1,
+        quest: 2,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[derive(Clone, Copy, Debug)]
 enum Command<ID, R, S> {
     Add(ID, (R, S), (R, S)),
@@ -26,22 +26,24 @@ pub fn part_1(data: &str) -> i64 {
         .sum()
 }
 
+/// The days the snail at `(x, y)` is at `y = 1`: after `y - 1` days, then
+/// every `x + y - 1` days, the length of its diagonal.
+fn congruence(line: &str) -> (u64, u64) {
+    let (x, y) = line.split_once(' ').expect("Invalid line");
+    let (x, y) = (parse_coordinate(x), parse_coordinate(y));
+    let (x, y) = (
+        u64::try_from(x).expect("Invalid coordinate"),
+        u64::try_from(y).expect("Invalid coordinate"),
+    );
+
+    (y - 1, x + y - 1)
+}
+
 /// # Panics
 #[must_use]
-pub fn part_2(data: &str) -> i64 {
-    data.lines()
-        .map(|line| {
-            let (x, y) = line.split_once(' ').expect("Invalid line");
-            (parse_coordinate(x), parse_coordinate(y))
-        })
-        .map(|(x, y)| (y - 1, x + y - 1))
-        .reduce(|(mut x1, m1), (x2, m2)| {
-            while x1 % m2 != x2 {
-                x1 += m1;
-            }
-            (x1, num::integer::lcm(m1, m2))
-        })
-        .unwrap()
+pub fn part_2(data: &str) -> u64 {
+    numtheory::crt(data.lines().map(congruence))
+        .expect("No solution")
         .0
 }
 
@@ -51,27 +53,41 @@ pub use part_2 as part_3;
 /// # Panics
 #[must_use]
 #[cfg(feature = "rayon")]
-pub fn part_3(data: &str) -> i64 {
+pub fn part_3(data: &str) -> u64 {
     use rayon::prelude::*;
 
     data.par_lines()
-        .map(|line| {
-            let (x, y) = line.split_once(' ').expect("Invalid line");
-            (parse_coordinate(x), parse_coordinate(y))
-        })
-        .map(|(x, y)| (y - 1, x + y - 1))
+        .map(congruence)
         .reduce(
             || (0, 1),
-            |(mut x1, m1), (x2, m2)| {
-                while x1 % m2 != x2 {
-                    x1 += m1;
-                }
-                (x1, num::integer::lcm(m1, m2))
-            },
+            |a, b| numtheory::crt([a, b]).expect("No solution"),
         )
         .0
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 1,
+        quest: 3,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,254 +271,46 @@ where
     new_current
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let data = r"*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-*.*.*...*.*...*..
-.*.*.*.*.*...*.*.
-*.*.....*...*.*.*
-.*.*.*.*.*.*.*.*.
-*...*...*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-*.*.*...*.*.*.*.*
-.*...*...*.*.*.*.
-*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-
-RRRLRLRRRRRL
-LLLLRLRRRRRR
-RLLLLLRLRLRL
-LRLLLRRRLRLR
-LLRLLRLLLRRL
-LRLRLLLRRRRL
-LRLLLLLLRLLL
-RRLLLRLLRLRR
-RLLLLLRLLLRL";
-
-        assert_eq!(part_1(data), 26);
-    }
+pub struct Solution;
 
-    #[test]
-    fn test_part_2() {
-        let data = r"*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-..*.*.*.*...*.*...*.*.*..
-.*...*.*.*.*.*.*.....*.*.
-*.*...*.*.*.*.*.*...*.*.*
-.*.*.*.*.*.*.*.*.......*.
-*.*.*.*.*.*.*.*.*.*...*..
-.*.*.*.*.*.*.*.*.....*.*.
-*.*...*.*.*.*.*.*.*.*....
-.*.*.*.*.*.*.*.*.*.*.*.*.
-*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*...*.*.
-*.*.*.*.*.*.*.*.*...*.*.*
-.*.*.*.*.*.*.*.*.....*.*.
-*.*.*.*.*.*.*.*...*...*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-*.*.*...*.*.*.*.*.*.*.*.*
-.*...*.*.*.*...*.*.*...*.
-*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-
-RRRLLRRRLLRLRRLLLRLR
-RRRRRRRRRRLRRRRRLLRR
-LLLLLLLLRLRRLLRRLRLL
-RRRLLRRRLLRLLRLLLRRL
-RLRLLLRRLRRRLRRLRRRL
-LLLLLLLLRLLRRLLRLLLL
-LRLLRRLRLLLLLLLRLRRL
-LRLLRRLLLRRRRRLRRLRR
-LRLLRRLRLLRLRRLLLRLL
-RLLRRRRLRLRLRLRLLRRL";
-
-        assert_eq!(part_2(data), 115);
-    }
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 2,
+        quest: 1,
+    };
 
-    #[test]
-    fn test_part_3_1_bf() {
-        let data = r"*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-*.*.*...*.*...*..
-.*.*.*.*.*...*.*.
-*.*.....*...*.*.*
-.*.*.*.*.*.*.*.*.
-*...*...*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-*.*.*...*.*.*.*.*
-.*...*...*.*.*.*.
-*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-
-RRRLRLRRRRRL
-LLLLRLRRRRRR
-RLLLLLRLRLRL
-LRLLLRRRLRLR
-LLRLLRLLLRRL
-LRLRLLLRRRRL";
-
-        assert_eq!(&part_3_bf(data), "13 43");
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
     }
 
-    #[test]
-    fn test_part_3_2_bf() {
-        let data = r"*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-..*.*.*.*...*.*...*.*.*..
-.*...*.*.*.*.*.*.....*.*.
-*.*...*.*.*.*.*.*...*.*.*
-.*.*.*.*.*.*.*.*.......*.
-*.*.*.*.*.*.*.*.*.*...*..
-.*.*.*.*.*.*.*.*.....*.*.
-*.*...*.*.*.*.*.*.*.*....
-.*.*.*.*.*.*.*.*.*.*.*.*.
-*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*...*.*.
-*.*.*.*.*.*.*.*.*...*.*.*
-.*.*.*.*.*.*.*.*.....*.*.
-*.*.*.*.*.*.*.*...*...*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-*.*.*...*.*.*.*.*.*.*.*.*
-.*...*.*.*.*...*.*.*...*.
-*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-
-RRRLLRRRLLRLRRLLLRLR
-RRRRRRRRRRLRRRRRLLRR
-LLLLLLLLRLRRLLRRLRLL
-RRRLLRRRLLRLLRLLLRRL
-RLRLLLRRLRRRLRRLRRRL
-LLLLLLLLRLLRRLLRLLLL";
-
-        assert_eq!(&part_3(data), "25 66");
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
     }
 
-    #[test]
-    fn test_part_3_3_bf() {
-        let data = r"*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.
-..*.*.*.*.*.*.........*.*.*.*.....*.*.*
-.*.*...*.*.*.*.*.*.*.*.*.*.*...*.*.*.*.
-*.*.*.*...*.*.*.*.*.....*.*.*.*...*.*..
-.*...*.*...*.*.*.*.*.*.*.....*.*.*.*.*.
-*.*.*.*.*.....*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*...*.*.*.*.....*.*.*.*...*.
-*.*...*.*.*.*.*.*.*.*...*.*.*...*.*.*.*
-.*...*.*.*.*.*.*.*.*...*.*.*.*.*.*.*.*.
-*.*.*.*.*.*...*.....*.*...*...*.*.*.*.*
-.*...*.*.*.*.*...*.*.*.*.*...*.*...*.*.
-*.*.*.*.*...*.*.*.*.*.*.*.*...*.*.*.*.*
-.*.*.*.*.*.*.*.*...*.*.*.*.*.*.*.*.*.*.
-....*.*.*.*...*.*.*.*.*.*.*...*.*.*...*
-.*.*.*...*.*.*.*.*...*.*.*.*.*.*.*.*...
-*.*.*.*.*.*.*.....*...*...*.*.*.*.*.*.*
-.*.*...*.....*.*.*.*.*.*.*...*.*.*.*.*.
-*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.
-
-RRRRLLRRLLLLLLLRLLRL
-RRRRRRRLRRLRRLRRRLRR
-RRRLLRRRRRLRRRRRLRRR
-LLLLRRLLRRLLLLLRRLLL
-LRRRRLRRLRLLRLLRRLRR
-RRRRRRRRLRRRRLLRRRLR";
-
-        assert_eq!(&part_3(data), "39 122");
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
     }
+}
 
-    #[test]
-    fn test_part_3_1_fast() {
-        let data = r"*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-*.*.*...*.*...*..
-.*.*.*.*.*...*.*.
-*.*.....*...*.*.*
-.*.*.*.*.*.*.*.*.
-*...*...*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-*.*.*...*.*.*.*.*
-.*...*...*.*.*.*.
-*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.
-
-RRRLRLRRRRRL
-LLLLRLRRRRRR
-RLLLLLRLRLRL
-LRLLLRRRLRLR
-LLRLLRLLLRRL
-LRLRLLLRRRRL";
-
-        assert_eq!(&part_3_fast(data), "13 43");
-    }
+everybody_codes_quest::register!(Solution);
+everybody_codes_quest::sample_tests!(Solution);
 
-    #[test]
-    fn test_part_3_2_fast() {
-        let data = r"*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-..*.*.*.*...*.*...*.*.*..
-.*...*.*.*.*.*.*.....*.*.
-*.*...*.*.*.*.*.*...*.*.*
-.*.*.*.*.*.*.*.*.......*.
-*.*.*.*.*.*.*.*.*.*...*..
-.*.*.*.*.*.*.*.*.....*.*.
-*.*...*.*.*.*.*.*.*.*....
-.*.*.*.*.*.*.*.*.*.*.*.*.
-*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*...*.*.
-*.*.*.*.*.*.*.*.*...*.*.*
-.*.*.*.*.*.*.*.*.....*.*.
-*.*.*.*.*.*.*.*...*...*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-*.*.*...*.*.*.*.*.*.*.*.*
-.*...*.*.*.*...*.*.*...*.
-*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.
-
-RRRLLRRRLLRLRRLLLRLR
-RRRRRRRRRRLRRRRRLLRR
-LLLLLLLLRLRRLLRRLRLL
-RRRLLRRRLLRLLRLLLRRL
-RLRLLLRRLRRRLRRLRRRL
-LLLLLLLLRLLRRLLRLLLL";
-
-        assert_eq!(&part_3_fast(data), "25 66");
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
-    fn test_part_3_3_fast() {
-        let data = r"*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.
-..*.*.*.*.*.*.........*.*.*.*.....*.*.*
-.*.*...*.*.*.*.*.*.*.*.*.*.*...*.*.*.*.
-*.*.*.*...*.*.*.*.*.....*.*.*.*...*.*..
-.*...*.*...*.*.*.*.*.*.*.....*.*.*.*.*.
-*.*.*.*.*.....*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*...*.*.*.*.....*.*.*.*...*.
-*.*...*.*.*.*.*.*.*.*...*.*.*...*.*.*.*
-.*...*.*.*.*.*.*.*.*...*.*.*.*.*.*.*.*.
-*.*.*.*.*.*...*.....*.*...*...*.*.*.*.*
-.*...*.*.*.*.*...*.*.*.*.*...*.*...*.*.
-*.*.*.*.*...*.*.*.*.*.*.*.*...*.*.*.*.*
-.*.*.*.*.*.*.*.*...*.*.*.*.*.*.*.*.*.*.
-....*.*.*.*...*.*.*.*.*.*.*...*.*.*...*
-.*.*.*...*.*.*.*.*...*.*.*.*.*.*.*.*...
-*.*.*.*.*.*.*.....*...*...*.*.*.*.*.*.*
-.*.*...*.....*.*.*.*.*.*.*...*.*.*.*.*.
-*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*
-.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.
-
-RRRRLLRRLLLLLLLRLLRL
-RRRRRRRLRRLRRLRRRLRR
-RRRLLRRRRRLRRRRRLRRR
-LLLLRRLLRRLLLLLRRLLL
-LRRRRLRRLRLLRLLRRLRR
-RRRRRRRRLRRRRLLRRRLR";
-
-        assert_eq!(&part_3_fast(data), "39 122");
+    fn test_part_3_bf() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/samples");
+        let samples = everybody_codes::samples::find(dir.as_ref()).unwrap();
+
+        for sample in samples.iter().filter(|sample| sample.part == 3) {
+            assert_eq!(
+                part_3_bf(&sample.read_input().unwrap()),
+                sample.read_answer().unwrap(),
+                "{}",
+                sample.name()
+            );
+        }
     }
 }
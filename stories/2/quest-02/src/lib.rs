@@ -73,6 +73,33 @@ pub fn part_3(data: &str) -> usize {
     part_2::<100_000>(data)
 }
 
+pub struct Solution;
+
+impl Solution {
+    pub const PART_2_REPEAT: usize = 100;
+}
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 2,
+        quest: 2,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2::<{ Solution::PART_2_REPEAT }>(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,7 +53,7 @@ pub fn part_2(data: &str) -> u64 {
 }
 
 /// # Panics
-#[allow(clippy::similar_names)]
+#[allow(clippy::similar_names, clippy::get_first)]
 #[must_use]
 pub fn part_3(data: &str) -> u64 {
     use core::sync::atomic::{self, AtomicU64, Ordering};
@@ -120,6 +120,29 @@ pub fn part_3(data: &str) -> u64 {
     max_scale
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 3,
+        quest: 1,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
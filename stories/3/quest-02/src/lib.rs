@@ -1,7 +1,6 @@
 #![no_std]
 
-mod bitset;
-use bitset::BitSet;
+type BitSet = grid::BitGrid<128, 128>;
 
 type Deque<T> = heapless::Deque<T, 1024>;
 
@@ -32,11 +31,11 @@ pub fn part_1(data: &str) -> u64 {
 
     let mut steps = 0;
     let mut set = BitSet::new();
-    set.insert(start);
+    set.insert(wrap(start));
     loop {
         let (dr, dc) = directions.next().unwrap();
         let next_position = (start.0 + dr, start.1 + dc);
-        if !set.insert(next_position) {
+        if !set.insert(wrap(next_position)) {
             continue;
         }
         steps += 1;
@@ -49,6 +48,11 @@ pub fn part_1(data: &str) -> u64 {
     steps
 }
 
+#[allow(clippy::cast_possible_truncation)]
+const fn wrap((r, c): (i64, i64)) -> grid::Pos {
+    BitSet::wrap(r as isize, c as isize)
+}
+
 fn p_min((r1, c1): (i64, i64), (r2, c2): (i64, i64)) -> (i64, i64) {
     (r1.min(r2), c1.min(c2))
 }
@@ -64,17 +68,17 @@ fn fill(
     max: (i64, i64),
     position: (i64, i64),
 ) -> bool {
-    filler.insert(position);
+    filler.insert(wrap(position));
 
     let mut queue = Deque::try_from([position]).unwrap();
     while let Some(position) = queue.pop_front() {
         for (dr, dc) in DIRECTIONS {
             let position = (position.0 + dr, position.1 + dc);
-            if !set.contains(&position) {
+            if !set.contains(wrap(position)) {
                 if (min.0 + 1..max.0).contains(&position.0)
                     && (min.1 + 1..max.1).contains(&position.1)
                 {
-                    if filler.insert(position) {
+                    if filler.insert(wrap(position)) {
                         queue.push_back(position).unwrap();
                     }
                 } else {
@@ -101,9 +105,9 @@ pub fn solve<'a>(data: &str, mut directions: impl Iterator<Item = &'a (i64, i64)
     let mut max = (i64::MIN, i64::MIN);
 
     let mut init = |position| {
-        set.insert(position);
+        set.insert(wrap(position));
         for (dr, dc) in DIRECTIONS {
-            perimeter.insert((position.0 + dr, position.1 + dc));
+            perimeter.insert(wrap((position.0 + dr, position.1 + dc)));
         }
 
         min = p_min(min, position);
@@ -131,36 +135,36 @@ pub fn solve<'a>(data: &str, mut directions: impl Iterator<Item = &'a (i64, i64)
     // filling holes
     for r in min.0..=max.0 {
         for c in min.1..=max.1 {
-            if set.contains(&(r, c)) {
+            if set.contains(wrap((r, c))) {
                 continue;
             }
 
             let mut filler = BitSet::new();
             if fill(&mut filler, &set, min, max, (r, c)) {
-                set.union(&filler);
+                set |= filler;
             }
         }
     }
 
     // fix perimeter
-    perimeter.difference(&set);
+    perimeter &= !set;
 
     while !perimeter.is_empty() {
         let (dr, dc) = directions.next().unwrap();
         let next_position = (start.0 + dr, start.1 + dc);
-        if !set.insert(next_position) {
+        if !set.insert(wrap(next_position)) {
             continue;
         }
 
         start = next_position;
-        perimeter.remove(&start);
+        perimeter.remove(wrap(start));
 
         min = p_min(min, start);
         max = p_max(max, start);
 
         for (dr, dc) in DIRECTIONS {
             let position = (start.0 + dr, start.1 + dc);
-            if set.contains(&position) {
+            if set.contains(wrap(position)) {
                 continue;
             }
 
@@ -168,8 +172,8 @@ pub fn solve<'a>(data: &str, mut directions: impl Iterator<Item = &'a (i64, i64)
             {
                 let mut filler = BitSet::new();
                 if fill(&mut filler, &set, min, max, position) {
-                    set.union(&filler);
-                    perimeter.difference(&filler);
+                    set |= filler;
+                    perimeter &= !filler;
                 }
             }
         }
@@ -210,6 +214,29 @@ pub fn part_3(data: &str) -> u64 {
     )
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 3,
+        quest: 2,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;
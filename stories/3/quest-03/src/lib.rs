@@ -16,66 +16,75 @@ struct Node<'a> {
 
 impl Node<'_> {}
 
-struct Nodes<'a, const SIZE: usize> {
-    nodes: [core::mem::MaybeUninit<Node<'a>>; SIZE],
-    next: usize,
+struct Nodes<'a, 'm> {
+    nodes: mem::MemVec<'m, Node<'a>>,
 }
 
-impl<'a, const SIZE: usize> Nodes<'a, SIZE> {
+impl<'a, 'm> Nodes<'a, 'm> {
     const fn new() -> Self {
         Self {
-            nodes: [const { core::mem::MaybeUninit::uninit() }; SIZE],
-            next: 0,
+            nodes: mem::MemVec::new(),
         }
     }
 
+    fn push(
+        &mut self,
+        mem: &mut mem::Mem<'m>,
+        id: u64,
+        plug: Socket<'a>,
+        left: Socket<'a>,
+        right: Socket<'a>,
+    ) -> Result<usize, mem::Oom> {
+        self.nodes.push(
+            mem,
+            Node {
+                id,
+                plug,
+                left,
+                right,
+                left_index: 0,
+                left_strong: false,
+                right_index: 0,
+                right_strong: false,
+            },
+        )?;
+
+        Ok(self.nodes.len() - 1)
+    }
+
     fn insert(
         &mut self,
+        mem: &mut mem::Mem<'m>,
         compatible_bond: impl Fn(Socket<'a>, Socket<'a>) -> bool,
         id: u64,
         plug: Socket<'a>,
         left: Socket<'a>,
         right: Socket<'a>,
-    ) {
-        self.nodes[self.next].write(Node {
-            id,
-            plug,
-            left,
-            right,
-            left_index: 0,
-            left_strong: false,
-            right_index: 0,
-            right_strong: false,
-        });
+    ) -> Result<(), mem::Oom> {
+        let new = self.push(mem, id, plug, left, right)?;
 
-        if self.next > 0 {
-            // SAFETY: root exists
-            if !unsafe { self.unsafe_insert(&compatible_bond, 0, plug) } {
-                unreachable!("cannot insert {id}");
-            }
+        if new > 0 && !self.insert_r(&compatible_bond, 0, new, plug) {
+            unreachable!("cannot insert {id}");
         }
 
-        self.next += 1;
+        Ok(())
     }
 
-    fn insert_break(&mut self, id: u64, plug: Socket<'a>, left: Socket<'a>, right: Socket<'a>) {
-        self.nodes[self.next].write(Node {
-            id,
-            plug,
-            left,
-            right,
-            left_index: 0,
-            left_strong: false,
-            right_index: 0,
-            right_strong: false,
-        });
+    #[allow(clippy::while_let_loop)]
+    fn insert_break(
+        &mut self,
+        mem: &mut mem::Mem<'m>,
+        id: u64,
+        plug: Socket<'a>,
+        left: Socket<'a>,
+        right: Socket<'a>,
+    ) -> Result<(), mem::Oom> {
+        let mut new = self.push(mem, id, plug, left, right)?;
 
-        if self.next > 0 {
-            let mut new = self.next;
+        if new > 0 {
             let mut plug = plug;
             loop {
-                // SAFETY: root exists
-                let Some((n, p)) = (unsafe { self.unsafe_insert_break(0, new, plug) }) else {
+                let Some((n, p)) = self.insert_break_r(0, new, plug) else {
                     break;
                 };
 
@@ -86,127 +95,119 @@ impl<'a, const SIZE: usize> Nodes<'a, SIZE> {
             }
         }
 
-        self.next += 1;
+        Ok(())
     }
 
     fn visit(&self, mut visitor: impl FnMut(&Node<'a>)) {
-        if self.next > 0 {
-            // SAFETY: root exists
-            unsafe {
-                self.unsafe_visit(0, &mut visitor);
-            }
+        if !self.nodes.is_empty() {
+            self.visit_r(0, &mut visitor);
         }
     }
 
-    /// # Safety: internal function
-    unsafe fn unsafe_insert(
+    fn insert_r(
         &mut self,
         compatible_bond: &impl Fn(Socket<'a>, Socket<'a>) -> bool,
         current: usize,
+        new: usize,
         plug: Socket<'a>,
     ) -> bool {
-        // SAFETY: Internal function
-        unsafe {
-            {
-                let node = self.nodes[current].assume_init_mut();
-                let left_index = node.left_index;
-                if left_index == 0 {
-                    if compatible_bond(plug, node.left) {
-                        node.left_index = self.next;
-                        return true;
-                    }
-                } else if self.unsafe_insert(compatible_bond, left_index, plug) {
+        {
+            let node = &mut self.nodes[current];
+            let left_index = node.left_index;
+            if left_index == 0 {
+                if compatible_bond(plug, node.left) {
+                    node.left_index = new;
                     return true;
                 }
+            } else if self.insert_r(compatible_bond, left_index, new, plug) {
+                return true;
             }
+        }
 
-            {
-                let node = self.nodes[current].assume_init_mut();
-                let right_index = node.right_index;
-                if right_index == 0 {
-                    if compatible_bond(plug, node.right) {
-                        node.right_index = self.next;
-                        return true;
-                    }
-                } else if self.unsafe_insert(compatible_bond, right_index, plug) {
+        {
+            let node = &mut self.nodes[current];
+            let right_index = node.right_index;
+            if right_index == 0 {
+                if compatible_bond(plug, node.right) {
+                    node.right_index = new;
                     return true;
                 }
+            } else if self.insert_r(compatible_bond, right_index, new, plug) {
+                return true;
             }
         }
 
         false
     }
 
-    /// # Safety: internal function
-    unsafe fn unsafe_insert_break(
+    fn insert_break_r(
         &mut self,
         current: usize,
         mut new: usize,
         mut plug: Socket<'a>,
     ) -> Option<(usize, Socket<'a>)> {
-        // SAFETY: Internal function
-        unsafe {
-            {
-                let node = self.nodes[current].assume_init_mut();
-                let left_index = node.left_index;
-                let left_strong = node.left_strong;
-                if left_index == 0 {
-                    if weak_bond(plug, node.left) {
-                        node.left_index = new;
-                        node.left_strong = strong_bond(plug, node.left);
-                        return None;
-                    }
-                } else if !left_strong && strong_bond(plug, node.left) {
+        {
+            let node = &mut self.nodes[current];
+            let left_index = node.left_index;
+            let left_strong = node.left_strong;
+            if left_index == 0 {
+                if weak_bond(plug, node.left) {
                     node.left_index = new;
-                    node.left_strong = true;
-
-                    new = left_index;
-                    plug = self.nodes[new].assume_init_ref().plug;
-                } else {
-                    (new, plug) = self.unsafe_insert_break(left_index, new, plug)?;
+                    node.left_strong = strong_bond(plug, node.left);
+                    return None;
                 }
+            } else if !left_strong && strong_bond(plug, node.left) {
+                node.left_index = new;
+                node.left_strong = true;
+
+                new = left_index;
+                plug = self.nodes[new].plug;
+            } else {
+                (new, plug) = self.insert_break_r(left_index, new, plug)?;
             }
+        }
 
-            {
-                let node = self.nodes[current].assume_init_mut();
-                let right_index = node.right_index;
-                let right_strong = node.right_strong;
-                if right_index == 0 {
-                    if weak_bond(plug, node.right) {
-                        node.right_index = new;
-                        node.right_strong = strong_bond(plug, node.right);
-                        return None;
-                    }
-                } else if !right_strong && strong_bond(plug, node.right) {
+        {
+            let node = &mut self.nodes[current];
+            let right_index = node.right_index;
+            let right_strong = node.right_strong;
+            if right_index == 0 {
+                if weak_bond(plug, node.right) {
                     node.right_index = new;
-                    node.right_strong = true;
-
-                    new = right_index;
-                    plug = self.nodes[new].assume_init_ref().plug;
-                } else {
-                    (new, plug) = self.unsafe_insert_break(right_index, new, plug)?;
+                    node.right_strong = strong_bond(plug, node.right);
+                    return None;
                 }
+            } else if !right_strong && strong_bond(plug, node.right) {
+                node.right_index = new;
+                node.right_strong = true;
+
+                new = right_index;
+                plug = self.nodes[new].plug;
+            } else {
+                (new, plug) = self.insert_break_r(right_index, new, plug)?;
             }
         }
 
         Some((new, plug))
     }
 
-    /// # Safety: internal function
-    unsafe fn unsafe_visit(&self, current: usize, visitor: &mut impl FnMut(&Node<'a>)) {
-        unsafe {
-            let node = self.nodes[current].assume_init_ref();
-            if node.left_index > 0 {
-                self.unsafe_visit(node.left_index, visitor);
-            }
-            visitor(node);
-            if node.right_index > 0 {
-                self.unsafe_visit(node.right_index, visitor);
-            }
+    fn visit_r(&self, current: usize, visitor: &mut impl FnMut(&Node<'a>)) {
+        let node = &self.nodes[current];
+        if node.left_index > 0 {
+            self.visit_r(node.left_index, visitor);
+        }
+        visitor(node);
+        if node.right_index > 0 {
+            self.visit_r(node.right_index, visitor);
         }
     }
 }
 
+/// The bytes needed for `nodes` nodes, aligned.
+const fn pool_size(nodes: usize) -> usize {
+    core::mem::size_of::<Node>() * nodes + core::mem::align_of::<Node>()
+}
+
 fn weak_bond<'a>(plug: Socket<'a>, socket: Socket<'a>) -> bool {
     plug.0 == socket.0 || plug.1 == socket.1
 }
@@ -261,57 +262,86 @@ fn parse(line: &str) -> (u64, Socket<'_>, Socket<'_>, Socket<'_>, &'_ str) {
 
 /// # Panics
 #[must_use]
-fn solve<'a, const SIZE: usize>(data: &'a str, insert: impl Fn(&mut Nodes<'a, SIZE>, u64, Socket<'a>, Socket<'a>, Socket<'a>)) -> u64 {
-    let mut nodes = Nodes::<'a, SIZE>::new();
-    for line in data.lines() {
-        let (id, plug, left, right, _) = parse(line);
-
-        insert(&mut nodes, id, plug, left, right);
-    }
+fn solve<'a, const SIZE: usize>(
+    data: &'a str,
+    insert: impl for<'m> Fn(
+        &mut Nodes<'a, 'm>,
+        &mut mem::Mem<'m>,
+        u64,
+        Socket<'a>,
+        Socket<'a>,
+        Socket<'a>,
+    ) -> Result<(), mem::Oom>,
+) -> u64 {
+    let mut pool = [core::mem::MaybeUninit::uninit(); SIZE];
+
+    mem::Mem::with(&mut pool, |mut mem| {
+        let mut nodes = Nodes::new();
+        for line in data.lines() {
+            let (id, plug, left, right, _) = parse(line);
+
+            insert(&mut nodes, &mut mem, id, plug, left, right)?;
+        }
 
-    let mut checksum = 0;
-    let mut index = 1;
-    nodes.visit(|node| {
-        checksum += node.id * index;
-        index += 1;
-    });
+        let mut checksum = 0;
+        let mut index = 1;
+        nodes.visit(|node| {
+            checksum += node.id * index;
+            index += 1;
+        });
 
-    checksum
+        Ok(checksum)
+    })
+    .expect("too many nodes")
 }
 
 /// # Panics
 #[must_use]
-pub fn part_1<'a>(data: &'a str) -> u64 {
-    solve::<'a, 32>(
-        data,
-        |nodes, id, plug, left, right| {
-            nodes.insert(strong_bond, id, plug, left, right);
-        },
-    )
+pub fn part_1(data: &str) -> u64 {
+    solve::<{ pool_size(32) }>(data, |nodes, mem, id, plug, left, right| {
+        nodes.insert(mem, strong_bond, id, plug, left, right)
+    })
 }
 
 /// # Panics
 #[must_use]
-pub fn part_2<'a>(data: &'a str) -> u64 {
-    solve::<'a, 128>(
-        data,
-        |nodes, id, plug, left, right| {
-            nodes.insert(weak_bond, id, plug, left, right);
-        },
-    )
+pub fn part_2(data: &str) -> u64 {
+    solve::<{ pool_size(128) }>(data, |nodes, mem, id, plug, left, right| {
+        nodes.insert(mem, weak_bond, id, plug, left, right)
+    })
 }
 
 /// # Panics
 #[must_use]
-pub fn part_3<'a>(data: &'a str) -> u64 {
-    solve::<'a, 256>(
-        data,
-        |nodes, id, plug, left, right| {
-            nodes.insert_break(id, plug, left, right);
-        },
-    )
+pub fn part_3(data: &str) -> u64 {
+    solve::<{ pool_size(256) }>(data, |nodes, mem, id, plug, left, right| {
+        nodes.insert_break(mem, id, plug, left, right)
+    })
 }
 
+pub struct Solution;
+
+impl everybody_codes_quest::Quest for Solution {
+    const ID: everybody_codes_quest::PuzzleId = everybody_codes_quest::PuzzleId::Story {
+        number: 3,
+        quest: 3,
+    };
+
+    fn part_1(data: &str) -> impl core::fmt::Display {
+        part_1(data)
+    }
+
+    fn part_2(data: &str) -> impl core::fmt::Display {
+        part_2(data)
+    }
+
+    fn part_3(data: &str) -> impl core::fmt::Display {
+        part_3(data)
+    }
+}
+
+everybody_codes_quest::register!(Solution);
+
 #[cfg(test)]
 mod tests {
     use super::*;